@@ -20,9 +20,10 @@ pub mod motor;
 pub mod optical;
 pub mod rotation;
 pub mod rtos;
+pub mod screen;
 pub mod vision;
 
-use core::ffi::{c_char, c_int, c_void};
+use core::ffi::{c_char, c_int, c_long, c_void};
 
 pub use adi::*;
 pub use colors::*;
@@ -38,22 +39,90 @@ pub use motor::*;
 pub use optical::*;
 pub use rotation::*;
 pub use rtos::*;
-#[cfg(feaute = "apix")]
+pub use screen::*;
+#[cfg(feature = "xapi")]
 pub use serial::*;
 pub use vision::*;
-#[cfg(feaute = "apix")]
+#[cfg(feature = "xapi")]
 pub mod serial;
 
+// Fails to compile if the serial bindings are ever gated off while xapi is enabled.
+#[cfg(feature = "xapi")]
+const _: unsafe extern "C" fn(u8) -> i32 = serial_enable;
+
 pub const CLOCKS_PER_SEC: u32 = 1000;
 
+/// An opaque C standard library file stream.
+#[repr(C)]
+pub struct FILE {
+    _private: [u8; 0],
+}
+
+/// Statistics about the newlib heap, returned by [`mallinfo`].
+#[cfg(not(target_arch = "wasm32"))]
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct mallinfo {
+    /// The total space allocated from the system with `sbrk`.
+    pub arena: usize,
+    /// The number of free chunks.
+    pub ordblks: usize,
+    /// Unused, always zero.
+    pub smblks: usize,
+    /// Unused, always zero.
+    pub hblks: usize,
+    /// Unused, always zero.
+    pub hblkhd: usize,
+    /// Unused, always zero.
+    pub usmblks: usize,
+    /// Unused, always zero.
+    pub fsmblks: usize,
+    /// The total space in use by allocations.
+    pub uordblks: usize,
+    /// The total space in free chunks.
+    pub fordblks: usize,
+    /// The size of the free chunk at the top of the heap.
+    pub keepcost: usize,
+}
+
+pub const SEEK_SET: c_int = 0;
+pub const SEEK_CUR: c_int = 1;
+pub const SEEK_END: c_int = 2;
+
 extern "C" {
     #[cfg(not(target_arch = "wasm32"))]
     pub fn memalign(alignment: usize, size: usize) -> *mut c_void;
     #[cfg(not(target_arch = "wasm32"))]
     pub fn free(ptr: *mut c_void);
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn mallinfo() -> mallinfo;
+    /// Moves the end of the heap by `increment` bytes, returning the previous end.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn sbrk(increment: isize) -> *mut c_void;
+    /// The start of the heap, defined by the linker script.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub static _heap_start: u8;
+    /// The end of the heap, defined by the linker script.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub static _heap_end: u8;
     pub fn __errno() -> *mut i32;
     pub fn clock() -> i32;
     pub fn puts(s: *const c_char) -> i32;
     pub fn exit(code: i32) -> !;
+    pub fn read(fd: c_int, buf: *mut c_void, count: usize) -> isize;
     pub fn write(fd: c_int, buf: *const c_void, count: usize) -> isize;
+    pub fn fopen(path: *const c_char, mode: *const c_char) -> *mut FILE;
+    pub fn fclose(stream: *mut FILE) -> c_int;
+    pub fn fread(ptr: *mut c_void, size: usize, count: usize, stream: *mut FILE) -> usize;
+    pub fn fwrite(ptr: *const c_void, size: usize, count: usize, stream: *mut FILE) -> usize;
+    pub fn fseek(stream: *mut FILE, offset: c_long, whence: c_int) -> c_int;
+    pub fn ftell(stream: *mut FILE) -> c_long;
+    pub fn fflush(stream: *mut FILE) -> c_int;
+    pub fn ferror(stream: *mut FILE) -> c_int;
+    /// Writes the names of the entries in the SD card directory at `path` to `buffer`, separated by newlines.
+    ///
+    /// Returns a FatFs `FRESULT` code, which is 0 on success.
+    pub fn vexFileDirectoryGet(path: *const c_char, buffer: *mut c_char, len: u32) -> u32;
+    /// Returns 0 if nothing exists at `filename` on the SD card, 1 if it is a file, or 2 if it is a directory.
+    pub fn vexFileStatus(filename: *const c_char) -> u32;
 }
@@ -218,7 +218,7 @@ extern "C" {
         task: task_t,
         value: u32,
         action: notify_action_e_t,
-        prev_value: *const u32,
+        prev_value: *mut u32,
     ) -> u32;
     /** Waits for a notification to be nonzero.
 
@@ -322,6 +322,16 @@ extern "C" {
         xIndex: i32,
     ) -> *const core::ffi::c_void;
 
+    /** Returns the minimum amount of remaining stack space that was available to
+    the task since the task started executing, in words.
+
+    Parameters:
+        xTask  The handle of the task being queried. A task may query its own high water mark by passing NULL as the xTask parameter.
+
+    Returns:
+        The smallest amount of free stack space there has been (in words, so 4 bytes on the V5) since the task referenced by xTask was created. */
+    pub fn uxTaskGetStackHighWaterMark(xTask: task_t) -> u32;
+
     /// Suspends the scheduler.  Suspending the scheduler prevents a context switch from occurring but leaves interrupts enabled.  If an interrupt requests a context switch while the scheduler is suspended, then the request is held pending and is performed only when the scheduler is resumed (un-suspended).
     ///
     ///
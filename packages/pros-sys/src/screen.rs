@@ -0,0 +1,209 @@
+use core::ffi::c_uint;
+
+pub const E_TOUCH_RELEASED: c_uint = 0;
+pub const E_TOUCH_PRESSED: c_uint = 1;
+pub const E_TOUCH_HELD: c_uint = 2;
+pub const E_TOUCH_ERROR: c_uint = 3;
+pub type last_touch_e_t = c_uint;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct screen_touch_status_s_t {
+    /// Represents if the screen is being held, released, or pressed.
+    pub touch_status: last_touch_e_t,
+    /// Represents the x value of the location of the touch.
+    pub x: i16,
+    /// Represents the y value of the location of the touch.
+    pub y: i16,
+    /// Represents how many times the screen has be pressed.
+    pub press_count: i32,
+    /// Represents how many times the user released after a touch on the screen.
+    pub release_count: i32,
+}
+
+pub type touch_event_cb_fn_t = Option<unsafe extern "C" fn()>;
+
+extern "C" {
+    /**
+    Gets the touch status of the last touch of the screen.
+
+    This function uses the following values of errno when an error state is
+    reached:
+    EACCES - Another resource is currently trying to access the screen mutex.
+
+    \return The last_touch_e_t enum specifier that indicates the last touch
+    status of the screen (E_TOUCH_EVENT_RELEASE, E_TOUCH_EVENT_PRESS, or
+    E_TOUCH_EVENT_PRESS_AND_HOLD). This will be released by default if no action
+    was taken. If an error occured, the screen_touch_status_s_t will have its
+    last_touch_e_t enum specifier set to E_TOUCH_ERR, and other values set to
+    -1.
+    */
+    pub fn screen_touch_status() -> screen_touch_status_s_t;
+    /**
+    Assigns a callback function to be called when a certain touch event happens.
+
+    This function uses the following values of errno when an error state is
+    reached:
+    EACCES - Another resource is currently trying to access the screen mutex.
+
+    \param cb
+           Function pointer to callback when event type happens
+    \param event_type
+           Touch event that will trigger the callback.
+
+    \return 1 if there were no errors, or PROS_ERR if an error occured while
+    taking or returning the screen mutex.
+    */
+    pub fn screen_touch_callback(cb: touch_event_cb_fn_t, event_type: last_touch_e_t) -> u32;
+    /**
+    Set the pen color for subsequent graphics operations
+
+    This function uses the following values of errno when an error state is
+    reached:
+    EACCES - Another resource is currently trying to access the screen mutex.
+
+    \param color
+           The pen color to set (it is recommended to use values from the enum
+           defined in colors.h)
+
+    \return Returns 1 if the mutex was successfully returned, or PROS_ERR if
+    there was an error either taking or returning the screen mutex.
+    */
+    pub fn screen_set_pen(color: u32) -> u32;
+    /**
+    Set the eraser color for erasing and the current background.
+
+    This function uses the following values of errno when an error state is
+    reached:
+    EACCES - Another resource is currently trying to access the screen mutex.
+
+    \param color
+           The background color to set (it is recommended to use values
+           from the enum defined in colors.h)
+
+    \return Returns 1 if the mutex was successfully returned, or PROS_ERR
+    if there was an error either taking or returning the screen mutex.
+    */
+    pub fn screen_set_eraser(color: u32) -> u32;
+    /**
+    Get the current pen color.
+
+    This function uses the following values of errno when an error state is
+    reached:
+    EACCES - Another resource is currently trying to access the screen mutex.
+
+    \return The current pen color in the form of a value from the enum defined
+    in colors.h, or PROS_ERR if there was an error taking or returning
+    the screen mutex.
+    */
+    pub fn screen_get_pen() -> u32;
+    /**
+    Clear display with eraser color
+
+    This function uses the following values of errno when an error state is
+    reached:
+    EACCES - Another resource is currently trying to access the screen mutex.
+
+    \return 1 if there were no errors, or PROS_ERR if an error occured
+    taking or returning the screen mutex.
+    */
+    pub fn screen_erase() -> u32;
+    /**
+    Draw a single pixel on the screen using the current pen color
+
+    This function uses the following values of errno when an error state is
+    reached:
+    EACCES - Another resource is currently trying to access the screen mutex.
+
+    \param x
+           The x coordinate of the pixel
+    \param y
+           The y coordinate of the pixel
+
+    \return 1 if there were no errors, or PROS_ERR if an error occured
+    taking or returning the screen mutex.
+    */
+    pub fn screen_draw_pixel(x: i16, y: i16) -> u32;
+    /**
+    Draw a line on the screen using the current pen color
+
+    This function uses the following values of errno when an error state is
+    reached:
+    EACCES - Another resource is currently trying to access the screen mutex.
+
+    \param x0, y0
+           The (x, y) coordinates of the first point of the line
+    \param x1, y1
+           The (x, y) coordinates of the second point of the line
+
+    \return 1 if there were no errors, or PROS_ERR if an error occured
+    taking or returning the screen mutex.
+    */
+    pub fn screen_draw_line(x0: i16, y0: i16, x1: i16, y1: i16) -> u32;
+    /**
+    Draw a rectangle on the screen using the current pen color
+
+    This function uses the following values of errno when an error state is
+    reached:
+    EACCES - Another resource is currently trying to access the screen mutex.
+
+    \param x0, y0
+           The (x,y) coordinates of the first point of the rectangle
+    \param x1, y1
+           The (x,y) coordinates of the second point of the rectangle
+
+    \return 1 if there were no errors, or PROS_ERR if an error occured
+    taking or returning the screen mutex.
+    */
+    pub fn screen_draw_rect(x0: i16, y0: i16, x1: i16, y1: i16) -> u32;
+    /**
+    Fill a rectangular region of the screen using the current pen
+    color
+
+    This function uses the following values of errno when an error state is
+    reached:
+    EACCES - Another resource is currently trying to access the screen mutex.
+
+    \param x0, y0
+           The (x,y) coordinates of the first point of the rectangle
+    \param x1, y1
+           The (x,y) coordinates of the second point of the rectangle
+
+    \return 1 if there were no errors, or PROS_ERR if an error occured
+    taking or returning the screen mutex.
+    */
+    pub fn screen_fill_rect(x0: i16, y0: i16, x1: i16, y1: i16) -> u32;
+    /**
+    Draw a circle on the screen using the current pen color
+
+    This function uses the following values of errno when an error state is
+    reached:
+    EACCES - Another resource is currently trying to access the screen mutex.
+
+    \param x, y
+           The (x,y) coordinates of the center of the circle
+    \param r
+           The radius of the circle
+
+    \return 1 if there were no errors, or PROS_ERR if an error occured
+    taking or returning the screen mutex.
+    */
+    pub fn screen_draw_circle(x: i16, y: i16, radius: i16) -> u32;
+    /**
+    Fill a circular region of the screen using the current pen
+    color
+
+    This function uses the following values of errno when an error state is
+    reached:
+    EACCES - Another resource is currently trying to access the screen mutex.
+
+    \param x, y
+           The (x,y) coordinates of the center of the circle
+    \param r
+           The radius of the circle
+
+    \return 1 if there were no errors, or PROS_ERR if an error occured
+    taking or returning the screen mutex.
+    */
+    pub fn screen_fill_circle(x: i16, y: i16, radius: i16) -> u32;
+}
@@ -8,8 +8,8 @@ use core::time::Duration;
 
 use pros::{
     devices::{
+        controller::{Controller, ControllerId},
         smart::vision::{LedMode, Rgb, VisionZeroPoint},
-        Controller,
     },
     prelude::*,
     sync::Mutex,
@@ -46,9 +46,9 @@ impl AsyncRobot for ExampleRobot {
         self.motor.lock().wait_until_stopped().await?;
 
         // Create a controller, specifically controller 1.
-        let controller = Controller::Master;
+        let controller = Controller::new(ControllerId::Master);
 
-        self.vision.set_led(LedMode::On(Rgb::new(0, 0, 255)));
+        self.vision.set_led(LedMode::On(Rgb::new(0, 0, 255)))?;
 
         pros::lcd::buttons::register(left_button_callback, Button::Left);
 
@@ -0,0 +1,261 @@
+use alloc::{collections::VecDeque, sync::Arc};
+use core::{
+    fmt::{self, Debug, Display},
+    future::Future,
+    mem,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+use crate::sync::SchedulerLock;
+
+struct Shared<T> {
+    queue: VecDeque<T>,
+    capacity: usize,
+    senders: usize,
+    receiver_alive: bool,
+    recv_waker: Option<Waker>,
+    send_wakers: VecDeque<Waker>,
+}
+
+impl<T> Shared<T> {
+    /// Removes a queued sender's waker, returning `false` if it was not queued.
+    fn remove_sender(&mut self, waker: &Waker) -> bool {
+        match self.send_wakers.iter().position(|w| w.will_wake(waker)) {
+            Some(index) => {
+                self.send_wakers.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Creates a bounded multi-producer, single-consumer channel for communicating between async tasks.
+///
+/// The channel holds at most `capacity` items at once.
+/// Sending to a full channel waits until the receiver makes room.
+///
+/// # Panics
+///
+/// Panics if `capacity` is 0.
+pub fn channel<T>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    assert!(capacity > 0, "channel capacity must be greater than 0");
+
+    let shared = Arc::new(SchedulerLock::new(Shared {
+        queue: VecDeque::with_capacity(capacity),
+        capacity,
+        senders: 1,
+        receiver_alive: true,
+        recv_waker: None,
+        send_wakers: VecDeque::new(),
+    }));
+
+    (
+        Sender {
+            shared: shared.clone(),
+        },
+        Receiver { shared },
+    )
+}
+
+/// The sending half of a [`channel`]. Senders can be cloned to send from multiple tasks.
+pub struct Sender<T> {
+    shared: Arc<SchedulerLock<Shared<T>>>,
+}
+
+impl<T> Sender<T> {
+    /// Sends an item, waiting until there is room in the channel.
+    ///
+    /// Returns the item back in a [`SendError`] if the receiver has been dropped.
+    pub fn send(&self, item: T) -> SendFuture<'_, T> {
+        SendFuture {
+            sender: self,
+            item: Some(item),
+            waker: None,
+        }
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared.lock().senders += 1;
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let waker = {
+            let mut shared = self.shared.lock();
+            shared.senders -= 1;
+            if shared.senders == 0 {
+                shared.recv_waker.take()
+            } else {
+                None
+            }
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Debug for Sender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sender").finish_non_exhaustive()
+    }
+}
+
+/// The receiving half of a [`channel`].
+pub struct Receiver<T> {
+    shared: Arc<SchedulerLock<Shared<T>>>,
+}
+
+impl<T> Receiver<T> {
+    /// Receives the next item, waiting until one is available.
+    ///
+    /// Resolves to `None` once every [`Sender`] has been dropped and the channel is empty.
+    pub fn recv(&mut self) -> RecvFuture<'_, T> {
+        RecvFuture { receiver: self }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        let wakers = {
+            let mut shared = self.shared.lock();
+            shared.receiver_alive = false;
+            mem::take(&mut shared.send_wakers)
+        };
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Debug for Receiver<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Receiver").finish_non_exhaustive()
+    }
+}
+
+/// A future that sends an item on a [`channel`]. See [`Sender::send`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct SendFuture<'a, T> {
+    sender: &'a Sender<T>,
+    item: Option<T>,
+    waker: Option<Waker>,
+}
+
+impl<T> Unpin for SendFuture<'_, T> {}
+
+impl<T> Future for SendFuture<'_, T> {
+    type Output = Result<(), SendError<T>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut shared = self.sender.shared.lock();
+        let item = self
+            .item
+            .take()
+            .expect("SendFuture polled after completion");
+
+        // Once this future completes it is no longer queued, so it must not pass on wakeups when dropped.
+        if let Some(waker) = self.waker.take() {
+            shared.remove_sender(&waker);
+        }
+
+        if !shared.receiver_alive {
+            return Poll::Ready(Err(SendError(item)));
+        }
+
+        if shared.queue.len() < shared.capacity {
+            shared.queue.push_back(item);
+            let waker = shared.recv_waker.take();
+            drop(shared);
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+            return Poll::Ready(Ok(()));
+        }
+
+        shared.send_wakers.push_back(cx.waker().clone());
+        drop(shared);
+        self.item = Some(item);
+        self.waker = Some(cx.waker().clone());
+
+        Poll::Pending
+    }
+}
+
+impl<T> Drop for SendFuture<'_, T> {
+    fn drop(&mut self) {
+        if let Some(waker) = &self.waker {
+            let next = {
+                let mut shared = self.sender.shared.lock();
+                // If this future's waker is no longer queued, it was woken because there is room
+                // in the channel, so pass the wakeup on to the next sender.
+                if shared.remove_sender(waker) {
+                    None
+                } else {
+                    shared.send_wakers.pop_front()
+                }
+            };
+            if let Some(next) = next {
+                next.wake();
+            }
+        }
+    }
+}
+
+/// A future that receives an item from a [`channel`]. See [`Receiver::recv`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct RecvFuture<'a, T> {
+    receiver: &'a mut Receiver<T>,
+}
+
+impl<T> Future for RecvFuture<'_, T> {
+    type Output = Option<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut shared = self.receiver.shared.lock();
+
+        if let Some(item) = shared.queue.pop_front() {
+            let waker = shared.send_wakers.pop_front();
+            drop(shared);
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+            return Poll::Ready(Some(item));
+        }
+
+        if shared.senders == 0 {
+            return Poll::Ready(None);
+        }
+
+        shared.recv_waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+/// An error returned when sending on a [`channel`] whose [`Receiver`] has been dropped.
+///
+/// Contains the item that could not be sent.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SendError<T>(pub T);
+
+impl<T> Debug for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SendError").finish_non_exhaustive()
+    }
+}
+
+impl<T> Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("The receiving half of the channel has been dropped.")
+    }
+}
+
+impl<T> core::error::Error for SendError<T> {}
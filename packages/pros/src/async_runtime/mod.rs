@@ -3,22 +3,229 @@
 //! This runtime can be used outside of the main task, but it is reccomended to only use either
 //! real FreeRTOS tasks or this async runtime.
 
-use core::future::Future;
+use alloc::boxed::Box;
+use core::{
+    future::Future,
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
 
 use async_task::Task;
+pub use futures::future::Either;
+use snafu::Snafu;
 
+mod channel;
 pub(crate) mod executor;
 pub(crate) mod reactor;
 
+pub use channel::{channel, Receiver, RecvFuture, SendError, SendFuture, Sender};
+
+pub use crate::join;
+use crate::task::{sleep, SleepFuture};
+
 /// Runs a future in the background without having to await it
-/// To get the the return value you can await a task.
-pub fn spawn<T>(future: impl Future<Output = T> + 'static) -> Task<T> {
-    executor::EXECUTOR.with(|e| e.spawn(future))
+/// To get the the return value you can await the returned [`JoinHandle`].
+pub fn spawn<F: Future + 'static>(future: F) -> JoinHandle<F::Output> {
+    JoinHandle {
+        task: executor::EXECUTOR.with(|e| e.spawn(future)),
+    }
+}
+
+/// Races two futures, resolving to the output of whichever completes first.
+///
+/// Both futures are polled each time the returned future is polled, with `a` being polled first.
+/// If both are ready at the same time, `a` wins.
+///
+/// # Cancellation
+///
+/// Once one future completes, the other is dropped without being polled again.
+/// Any code after its current `.await` point will never run, so a cancelled future must not
+/// leave shared state (like a locked mutex or a half-finished movement) in a state that matters.
+///
+/// ```rust
+/// # use core::time::Duration;
+/// # use pros::prelude::*;
+/// # async fn example(motor: &mut Motor) {
+/// match select(motor.wait_until_stopped(), sleep(Duration::from_secs(2))).await {
+///     Either::Left(result) => println!("Motor stopped: {result:?}"),
+///     Either::Right(()) => println!("Gave up waiting for the motor"),
+/// }
+/// # }
+/// ```
+pub fn select<A: Future, B: Future>(a: A, b: B) -> Select<A, B> {
+    Select {
+        a: Box::pin(a),
+        b: Box::pin(b),
+    }
+}
+
+/// Waits for multiple futures to complete, resolving to a tuple of their outputs.
+///
+/// Unlike [`select`], which resolves as soon as one future completes, this waits for all of them.
+/// Every unfinished future is polled whenever the joined future is polled,
+/// and futures that have already completed are not polled again.
+///
+/// ```rust
+/// # use pros::prelude::*;
+/// # async fn example(left: &mut Motor, right: &mut Motor) {
+/// let (left_result, right_result) = join!(left.wait_until_stopped(), right.wait_until_stopped()).await;
+/// # }
+/// ```
+#[macro_export]
+macro_rules! join {
+    ($($future:expr),+ $(,)?) => {
+        $crate::__join_inner!([] $($future,)+)
+    };
+}
+
+// Binds each future to its own variable, one per expansion, so that they can be polled individually.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __join_inner {
+    ([$($bound:ident)*] $head:expr, $($rest:expr,)*) => {{
+        let mut future = $crate::async_runtime::MaybeDone::new($head);
+        $crate::__join_inner!([$($bound)* future] $($rest,)*)
+    }};
+    ([$($bound:ident)*]) => {
+        ::core::future::poll_fn(move |cx| {
+            let mut done = true;
+            $(done &= $bound.poll_done(cx);)*
+            if done {
+                ::core::task::Poll::Ready(($($bound.take_output(),)*))
+            } else {
+                ::core::task::Poll::Pending
+            }
+        })
+    };
+}
+
+/// Runs a future, giving up if it does not complete within `duration`.
+///
+/// Resolves to [`Elapsed`] if the timeout is reached first, in which case the future is dropped.
+/// The same cancellation caveats as [`select`] apply.
+pub fn timeout<F: Future>(duration: Duration, future: F) -> Timeout<F> {
+    Timeout {
+        future: Box::pin(future),
+        sleep: sleep(duration),
+    }
 }
 
 /// Blocks the current task untill a return value can be extracted from the provided future.
 /// Does not poll all futures to completion.
 /// If you want to complete all futures, use the [`complete_all`] function.
 pub fn block_on<F: Future + 'static>(future: F) -> F::Output {
-    executor::EXECUTOR.with(|e| e.block_on(spawn(future)))
+    executor::EXECUTOR.with(|e| e.block_on(e.spawn(future)))
+}
+
+/// A handle to a future spawned with [`spawn`].
+///
+/// Awaiting the handle resolves to the output of the spawned future.
+/// Dropping the handle cancels the future; use [`JoinHandle::detach`] to let it keep running instead.
+#[must_use = "dropping a JoinHandle cancels its task"]
+#[derive(Debug)]
+pub struct JoinHandle<T> {
+    task: Task<T>,
+}
+
+impl<T> JoinHandle<T> {
+    /// Returns true if the spawned future has completed.
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+
+    /// Lets the spawned future run to completion in the background without being awaited.
+    ///
+    /// The output of the future is dropped.
+    pub fn detach(self) {
+        self.task.detach();
+    }
+}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.task).poll(cx)
+    }
+}
+
+/// A future that races two futures. See [`select`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Select<A, B> {
+    a: Pin<Box<A>>,
+    b: Pin<Box<B>>,
+}
+
+impl<A: Future, B: Future> Future for Select<A, B> {
+    type Output = Either<A::Output, B::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if let Poll::Ready(output) = self.a.as_mut().poll(cx) {
+            return Poll::Ready(Either::Left(output));
+        }
+        if let Poll::Ready(output) = self.b.as_mut().poll(cx) {
+            return Poll::Ready(Either::Right(output));
+        }
+        Poll::Pending
+    }
+}
+
+/// A future that runs another future with a time limit. See [`timeout`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Timeout<F> {
+    future: Pin<Box<F>>,
+    sleep: SleepFuture,
+}
+
+impl<F: Future> Future for Timeout<F> {
+    type Output = Result<F::Output, Elapsed>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if let Poll::Ready(output) = self.future.as_mut().poll(cx) {
+            return Poll::Ready(Ok(output));
+        }
+        match Pin::new(&mut self.sleep).poll(cx) {
+            Poll::Ready(()) => Poll::Ready(Err(Elapsed)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// The error returned by [`timeout`] when the future did not complete in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Snafu)]
+#[snafu(display("The future did not complete before the timeout elapsed."))]
+pub struct Elapsed;
+
+/// A future that stores its output once it completes. Used by [`join!`].
+#[doc(hidden)]
+pub enum MaybeDone<F: Future> {
+    Pending(Pin<Box<F>>),
+    Done(F::Output),
+    Taken,
+}
+
+impl<F: Future> MaybeDone<F> {
+    pub fn new(future: F) -> Self {
+        Self::Pending(Box::pin(future))
+    }
+
+    /// Polls the future if it has not completed yet, returning whether it has completed.
+    pub fn poll_done(&mut self, cx: &mut Context<'_>) -> bool {
+        if let Self::Pending(future) = self {
+            match future.as_mut().poll(cx) {
+                Poll::Ready(output) => *self = Self::Done(output),
+                Poll::Pending => return false,
+            }
+        }
+        true
+    }
+
+    pub fn take_output(&mut self) -> F::Output {
+        match mem::replace(self, Self::Taken) {
+            Self::Done(output) => output,
+            _ => panic!("join! polled after completion"),
+        }
+    }
 }
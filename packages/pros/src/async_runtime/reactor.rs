@@ -1,17 +1,23 @@
-use alloc::collections::BTreeMap;
+use alloc::{collections::BTreeMap, vec::Vec};
 use core::task::Waker;
 
 pub struct Sleepers {
-    sleepers: BTreeMap<u32, Waker>,
+    // Multiple futures can sleep until the same target, so each target stores a list of wakers.
+    sleepers: BTreeMap<u32, Vec<Waker>>,
 }
 
 impl Sleepers {
     pub fn push(&mut self, waker: Waker, target: u32) {
-        self.sleepers.insert(target, waker);
+        self.sleepers.entry(target).or_default().push(waker);
     }
 
     pub fn pop(&mut self) -> Option<Waker> {
-        self.sleepers.pop_first().map(|(_, waker)| waker)
+        let mut entry = self.sleepers.first_entry()?;
+        let waker = entry.get_mut().pop();
+        if entry.get().is_empty() {
+            entry.remove();
+        }
+        waker
     }
 }
 
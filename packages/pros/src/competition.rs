@@ -1,5 +1,11 @@
 //! Utilities for getting what state of the competition the robot is in.
 //!
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
 use pros_sys::misc::{COMPETITION_AUTONOMOUS, COMPETITION_CONNECTED, COMPETITION_DISABLED};
 
 // TODO: change this to use PROS' internal version once we switch to PROS 4.
@@ -44,42 +50,105 @@ pub enum CompetitionSystem {
     /// Competition state is controlled by a VEX Field Controller.
     FieldControl,
 
-    // Competition state is controlled by a VEXnet competition switch.
+    /// Competition state is controlled by a VEXnet competition switch.
     CompetitionSwitch,
 }
 
+bitflags::bitflags! {
+    /// The status bits of the competition control system.
+    ///
+    /// Every field is read at the same time, so this can be used to get a consistent
+    /// snapshot of the competition state.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct CompetitionStatus: u8 {
+        /// The robot is disabled.
+        const DISABLED = COMPETITION_DISABLED;
+        /// The robot is in autonomous mode.
+        const AUTONOMOUS = COMPETITION_AUTONOMOUS;
+        /// The robot is connected to a competition control system.
+        const CONNECTED = COMPETITION_CONNECTED;
+        /// The competition control system is a competition switch rather than a field controller.
+        const COMPETITION_SWITCH = COMPETITION_SYSTEM;
+    }
+}
+
+impl CompetitionStatus {
+    /// Gets the competition mode, or phase.
+    pub const fn mode(&self) -> CompetitionMode {
+        if self.contains(Self::DISABLED) {
+            CompetitionMode::Disabled
+        } else if self.contains(Self::AUTONOMOUS) {
+            CompetitionMode::Autonomous
+        } else {
+            CompetitionMode::Opcontrol
+        }
+    }
+
+    /// Checks if the robot is connected to a competition control system.
+    pub const fn is_connected(&self) -> bool {
+        self.contains(Self::CONNECTED)
+    }
+
+    /// Gets the type of system controlling the robot's competition state, or [`None`] if the robot
+    /// is not tethered to a competition controller.
+    pub const fn system(&self) -> Option<CompetitionSystem> {
+        if !self.is_connected() {
+            None
+        } else if self.contains(Self::COMPETITION_SWITCH) {
+            Some(CompetitionSystem::CompetitionSwitch)
+        } else {
+            Some(CompetitionSystem::FieldControl)
+        }
+    }
+}
+
+/// Gets the current status of the competition control system.
+pub fn status() -> CompetitionStatus {
+    CompetitionStatus::from_bits_retain(unsafe { pros_sys::misc::competition_get_status() })
+}
+
 /// Gets the current competition mode, or phase.
 pub fn mode() -> CompetitionMode {
-    let status = unsafe { pros_sys::misc::competition_get_status() };
-
-    if status & COMPETITION_DISABLED != 0 {
-        CompetitionMode::Disabled
-    } else if status & COMPETITION_AUTONOMOUS != 0 {
-        CompetitionMode::Autonomous
-    } else {
-        CompetitionMode::Opcontrol
-    }
+    status().mode()
 }
 
 /// Checks if the robot is connected to a competition control system.
 pub fn connected() -> bool {
-    let status = unsafe { pros_sys::misc::competition_get_status() };
-
-    status & COMPETITION_CONNECTED != 0
+    status().is_connected()
 }
 
 /// Gets the type of system currently controlling the robot's competition state, or [`None`] if the robot
 /// is not tethered to a competition controller.
 pub fn system() -> Option<CompetitionSystem> {
-    let status = unsafe { pros_sys::misc::competition_get_status() };
+    status().system()
+}
 
-    if status & COMPETITION_CONNECTED != 0 {
-        if status & COMPETITION_SYSTEM == 0 {
-            Some(CompetitionSystem::FieldControl)
+/// Returns a future that completes when the competition mode changes, resolving to the new mode.
+///
+/// This can be used to cancel a routine as soon as the robot is disabled, for example by
+/// [`select`](crate::async_runtime::select)ing it against this future.
+pub fn wait_for_mode_change() -> ModeChangeFuture {
+    ModeChangeFuture { initial: mode() }
+}
+
+/// A future that completes when the competition mode changes.
+///
+/// Created by [`wait_for_mode_change`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ModeChangeFuture {
+    initial: CompetitionMode,
+}
+
+impl Future for ModeChangeFuture {
+    type Output = CompetitionMode;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mode = mode();
+        if mode != self.initial {
+            Poll::Ready(mode)
         } else {
-            Some(CompetitionSystem::CompetitionSwitch)
+            cx.waker().wake_by_ref();
+            Poll::Pending
         }
-    } else {
-        None
     }
 }
@@ -3,6 +3,11 @@ use pros_sys::PROS_ERR;
 use super::{AdiDevice, AdiDeviceType, AdiError, AdiPort};
 use crate::error::bail_on;
 
+/// A generic analog input, such as a pressure sensor or light sensor.
+///
+/// For sensors whose reading drifts over time, call [`AdiAnalogIn::calibrate`] while the sensor
+/// is at rest and use [`AdiAnalogIn::value_calibrated`] or [`AdiAnalogIn::value_calibrated_hr`]
+/// to read values relative to that baseline.
 #[derive(Debug, Eq, PartialEq)]
 pub struct AdiAnalogIn {
     port: AdiPort,
@@ -21,19 +26,17 @@ impl AdiAnalogIn {
     /// from approximately 500 samples, 1 ms apart, for a 0.5 s period of calibration.
     ///
     /// The average value thus calculated is returned and stored for later calls
-    /// to the value_calibrated and value_calibrated_hr functions.
+    /// to [`AdiAnalogIn::value_calibrated`] and [`AdiAnalogIn::value_calibrated_hr`].
     ///
     /// These functions will return the difference between this value and the current
     /// sensor value when called.
-    pub fn calibrate(&mut self) -> Result<(), AdiError> {
-        bail_on!(PROS_ERR, unsafe {
+    pub fn calibrate(&mut self) -> Result<i32, AdiError> {
+        Ok(bail_on!(PROS_ERR, unsafe {
             pros_sys::ext_adi_analog_calibrate(
                 self.port.internal_expander_index(),
                 self.port.index(),
             )
-        });
-
-        Ok(())
+        }))
     }
 
     /// Reads an analog input channel and returns the 12-bit value.
@@ -48,11 +51,11 @@ impl AdiAnalogIn {
 
     /// Reads the calibrated value of an analog input channel.
     ///
-    /// The calibrate function must be run first on that channel.
+    /// [`AdiAnalogIn::calibrate`] must be called first.
     ///
     /// This function is inappropriate for sensor values intended for integration,
     /// as round-off error can accumulate causing drift over time.
-    /// Use value_calbrated_hr instead.
+    /// Use [`AdiAnalogIn::value_calibrated_hr`] instead.
     pub fn value_calibrated(&self) -> Result<i32, AdiError> {
         Ok(bail_on!(PROS_ERR, unsafe {
             pros_sys::ext_adi_analog_read_calibrated(
@@ -62,9 +65,9 @@ impl AdiAnalogIn {
         }))
     }
 
-    /// Reads the calibrated value of an analog input channel 1-8 with enhanced precision.
+    /// Reads the calibrated value of an analog input channel with enhanced precision.
     ///
-    /// The calibrate function must be run first.
+    /// [`AdiAnalogIn::calibrate`] must be called first.
     ///
     /// This is intended for integrated sensor values such as gyros and accelerometers
     /// to reduce drift due to round-off, and should not be used on a sensor such as a
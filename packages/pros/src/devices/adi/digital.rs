@@ -1,8 +1,37 @@
+use core::{
+    future::Future,
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
 use pros_sys::PROS_ERR;
 
 use super::{AdiDevice, AdiDeviceType, AdiError, AdiPort};
 use crate::error::bail_on;
 
+/// A transition of a digital input between low and high.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Edge {
+    /// The input changed from low to high.
+    Rising,
+    /// The input changed from high to low.
+    Falling,
+    /// The input changed in either direction.
+    Either,
+}
+
+impl Edge {
+    const fn matches(self, previous: bool, current: bool) -> bool {
+        match self {
+            Self::Rising => !previous && current,
+            Self::Falling => previous && !current,
+            Self::Either => previous != current,
+        }
+    }
+}
+
 #[derive(Debug, Eq, PartialEq)]
 pub struct AdiDigitalIn {
     port: AdiPort,
@@ -15,6 +44,9 @@ impl AdiDigitalIn {
     }
 
     /// Gets a rising-edge case for a digital button press.
+    ///
+    /// Returns `true` only the first time this is called after the input goes high,
+    /// which is useful for homing against a limit switch in a synchronous loop.
     pub fn new_press(&mut self) -> Result<bool, AdiError> {
         Ok(unsafe {
             bail_on!(
@@ -39,6 +71,45 @@ impl AdiDigitalIn {
             ) != 0
         })
     }
+
+    /// Returns a future that completes when the input next changes in the direction of `edge`,
+    /// such as when a limit switch is pressed.
+    ///
+    /// The input is polled each time the executor runs, so short pulses between polls may be missed.
+    pub fn wait_for_edge(&self, edge: Edge) -> EdgeFuture<'_> {
+        EdgeFuture {
+            input: self,
+            edge,
+            last_value: None,
+        }
+    }
+}
+
+/// A future that completes when an [`AdiDigitalIn`] changes level.
+///
+/// Created by [`AdiDigitalIn::wait_for_edge`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[derive(Debug)]
+pub struct EdgeFuture<'a> {
+    input: &'a AdiDigitalIn,
+    edge: Edge,
+    last_value: Option<bool>,
+}
+
+impl<'a> Future for EdgeFuture<'a> {
+    type Output = Result<(), AdiError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let value = self.input.value()?;
+        let last_value = self.last_value.replace(value);
+
+        if last_value.is_some_and(|last_value| self.edge.matches(last_value, value)) {
+            return Poll::Ready(Ok(()));
+        }
+
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
 }
 
 impl AdiDevice for AdiDigitalIn {
@@ -60,23 +131,64 @@ impl AdiDevice for AdiDigitalIn {
 #[derive(Debug, Eq, PartialEq)]
 pub struct AdiDigitalOut {
     port: AdiPort,
+    value: bool,
 }
 
 impl AdiDigitalOut {
     /// Create a digital output from an [`AdiPort`].
+    ///
+    /// The output starts low.
     pub fn new(port: AdiPort) -> Self {
-        Self { port }
+        Self { port, value: false }
     }
 
     /// Sets the digital value (1 or 0) of a pin.
     pub fn set_value(&mut self, value: bool) -> Result<i32, AdiError> {
-        Ok(bail_on!(PROS_ERR, unsafe {
+        let result = bail_on!(PROS_ERR, unsafe {
             pros_sys::ext_adi_digital_write(
                 self.port.internal_expander_index(),
                 self.port.index(),
                 value,
             )
-        }))
+        });
+        self.value = value;
+        Ok(result)
+    }
+
+    /// Returns the value that was last written to the pin.
+    ///
+    /// Digital outputs cannot be read back from the hardware, so this is tracked by the struct.
+    pub fn value(&self) -> bool {
+        self.value
+    }
+
+    /// Flips the value of the pin.
+    pub fn toggle(&mut self) -> Result<(), AdiError> {
+        self.set_value(!self.value)?;
+        Ok(())
+    }
+
+    /// Sets the pin high for `duration` and then sets it low,
+    /// without blocking other tasks in the meantime.
+    ///
+    /// If the future is dropped before it finishes, the pin is still set low.
+    pub async fn pulse(&mut self, duration: Duration) -> Result<(), AdiError> {
+        self.set_value(true)?;
+        let guard = PulseGuard(self);
+        crate::task::sleep(duration).await;
+        guard.0.set_value(false)?;
+        mem::forget(guard);
+        Ok(())
+    }
+}
+
+/// Sets a pin low when a [`AdiDigitalOut::pulse`] future is dropped partway through.
+struct PulseGuard<'a>(&'a mut AdiDigitalOut);
+
+impl Drop for PulseGuard<'_> {
+    fn drop(&mut self) {
+        // There is no way to report an error from here, so a failed write is ignored.
+        _ = self.0.set_value(false);
     }
 }
 
@@ -1,12 +1,17 @@
+use core::time::Duration;
+
 use pros_sys::{ext_adi_gyro_t, PROS_ERR};
 
 use super::{AdiDevice, AdiDeviceType, AdiError, AdiPort};
-use crate::error::bail_on;
+use crate::{error::bail_on, time::Instant};
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, PartialEq)]
 pub struct AdiGyro {
     raw: ext_adi_gyro_t,
     port: AdiPort,
+    /// Estimated drift in tenths of a degree per second.
+    drift_rate: f64,
+    zeroed_at: Instant,
 }
 
 impl AdiGyro {
@@ -16,7 +21,12 @@ impl AdiGyro {
             pros_sys::ext_adi_gyro_init(port.internal_expander_index(), port.index(), multiplier)
         });
 
-        Ok(Self { raw, port })
+        Ok(Self {
+            raw,
+            port,
+            drift_rate: 0.0,
+            zeroed_at: Instant::now(),
+        })
     }
 
     /// Gets the current gyro angle in tenths of a degree. Unless a multiplier is applied to the gyro, the return value will be a whole number representing the number of degrees of rotation times 10.
@@ -29,12 +39,53 @@ impl AdiGyro {
     }
 
     /// Reset the current gyro angle to zero degrees.
+    ///
+    /// This also restarts drift compensation for [`AdiGyro::heading`],
+    /// but keeps the drift rate estimated by [`AdiGyro::calibrate`].
     pub fn zero(&mut self) -> Result<(), AdiError> {
         bail_on!(PROS_ERR.into(), unsafe {
             pros_sys::ext_adi_gyro_reset(self.raw)
         });
+        self.zeroed_at = Instant::now();
         Ok(())
     }
+
+    /// Estimates the gyro's drift by sampling it for `duration`.
+    ///
+    /// The gyro must be stationary for the entire duration, since any movement will be
+    /// counted as drift. This blocks the current task and zeroes the gyro.
+    /// The estimated drift is subtracted from [`AdiGyro::heading`].
+    pub fn calibrate(&mut self, duration: Duration) -> Result<(), AdiError> {
+        self.zero()?;
+        crate::task::delay(duration);
+
+        let elapsed = self.zeroed_at.elapsed().as_secs_f64();
+        self.drift_rate = if elapsed > 0.0 {
+            self.value()? / elapsed
+        } else {
+            0.0
+        };
+
+        self.zero()
+    }
+
+    /// Gets the drift-compensated heading of the gyro in degrees, wrapped to the range `[0, 360)`.
+    ///
+    /// Any multiplier passed to [`AdiGyro::new`] is applied before wrapping.
+    pub fn heading(&self) -> Result<f64, AdiError> {
+        let drift = self.drift_rate * self.zeroed_at.elapsed().as_secs_f64();
+        // Equivalent to `heading.rem_euclid(360.0)`, which is only available with `std`.
+        let mut heading = ((self.value()? - drift) / 10.0) % 360.0;
+        if heading < 0.0 {
+            heading += 360.0;
+        }
+        // Tiny negative headings round up to exactly 360 when wrapped.
+        if heading >= 360.0 {
+            heading = 0.0;
+        }
+
+        Ok(heading)
+    }
 }
 
 impl AdiDevice for AdiGyro {
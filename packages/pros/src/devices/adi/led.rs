@@ -0,0 +1,148 @@
+use alloc::vec::Vec;
+
+use pros_sys::{ext_adi_led_t, PROS_ERR};
+
+use super::{AdiDevice, AdiDeviceType, AdiError, AdiPort};
+use crate::{devices::smart::vision::Rgb, error::bail_on};
+
+/// Lookup table for gamma correcting color channels with a gamma of 2.2.
+const GAMMA_TABLE: [u8; 256] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2,
+    3, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 6, 6, 6, 6, 7, 7, 7, 8, 8, 8, 9, 9, 9, 10, 10, 11, 11,
+    11, 12, 12, 13, 13, 13, 14, 14, 15, 15, 16, 16, 17, 17, 18, 18, 19, 19, 20, 20, 21, 22, 22, 23,
+    23, 24, 25, 25, 26, 26, 27, 28, 28, 29, 30, 30, 31, 32, 33, 33, 34, 35, 35, 36, 37, 38, 39, 39,
+    40, 41, 42, 43, 43, 44, 45, 46, 47, 48, 49, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61,
+    62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 73, 74, 75, 76, 77, 78, 79, 81, 82, 83, 84, 85, 87, 88,
+    89, 90, 91, 93, 94, 95, 97, 98, 99, 100, 102, 103, 105, 106, 107, 109, 110, 111, 113, 114, 116,
+    117, 119, 120, 121, 123, 124, 126, 127, 129, 130, 132, 133, 135, 137, 138, 140, 141, 143, 145,
+    146, 148, 149, 151, 153, 154, 156, 158, 159, 161, 163, 165, 166, 168, 170, 172, 173, 175, 177,
+    179, 181, 182, 184, 186, 188, 190, 192, 194, 196, 197, 199, 201, 203, 205, 207, 209, 211, 213,
+    215, 217, 219, 221, 223, 225, 227, 229, 231, 234, 236, 238, 240, 242, 244, 246, 248, 251, 253,
+    255,
+];
+
+/// An addressable LED strip plugged into an [`AdiPort`].
+///
+/// Colors are written to an internal buffer with methods such as [`AdiLed::set_pixel`],
+/// and are only sent to the strip once [`AdiLed::update`] is called.
+#[derive(Debug, Eq, PartialEq)]
+pub struct AdiLed {
+    raw: ext_adi_led_t,
+    port: AdiPort,
+    buffer: Vec<Rgb>,
+    gamma_correction: bool,
+}
+
+impl AdiLed {
+    /// Create a new LED strip with the given number of pixels from an [`AdiPort`].
+    pub fn new(port: AdiPort, length: usize) -> Result<Self, AdiError> {
+        let raw = bail_on!(PROS_ERR, unsafe {
+            pros_sys::ext_adi_led_init(port.internal_expander_index(), port.index())
+        });
+
+        Ok(Self {
+            raw,
+            port,
+            buffer: alloc::vec![Rgb::default(); length],
+            gamma_correction: false,
+        })
+    }
+
+    /// Returns the number of pixels in the strip.
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns `true` if the strip has no pixels.
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Sets the number of pixels in the strip to match the physical strip.
+    ///
+    /// Newly added pixels are turned off.
+    pub fn set_length(&mut self, length: usize) {
+        self.buffer.resize(length, Rgb::default());
+    }
+
+    /// Returns the colors currently stored in the pixel buffer.
+    pub fn buffer(&self) -> &[Rgb] {
+        &self.buffer
+    }
+
+    /// Sets the color of a single pixel in the buffer.
+    pub fn set_pixel(&mut self, index: usize, color: Rgb) -> Result<(), AdiError> {
+        *self
+            .buffer
+            .get_mut(index)
+            .ok_or(AdiError::InvalidPixelIndex)? = color;
+        Ok(())
+    }
+
+    /// Sets every pixel in the buffer to the same color.
+    pub fn set_all(&mut self, color: Rgb) {
+        self.buffer.fill(color);
+    }
+
+    /// Copies colors into the buffer, starting from the first pixel.
+    ///
+    /// Any colors past the length of the strip are ignored.
+    pub fn set_buffer(&mut self, colors: impl IntoIterator<Item = Rgb>) {
+        for (pixel, color) in self.buffer.iter_mut().zip(colors) {
+            *pixel = color;
+        }
+    }
+
+    /// Sets whether or not colors should be gamma corrected when sent to the strip.
+    ///
+    /// Gamma correction makes changes in brightness appear more linear to the human eye.
+    pub fn set_gamma_correction(&mut self, gamma_correction: bool) {
+        self.gamma_correction = gamma_correction;
+    }
+
+    /// Returns whether or not colors are gamma corrected when sent to the strip.
+    pub fn gamma_correction(&self) -> bool {
+        self.gamma_correction
+    }
+
+    /// Sends the colors in the buffer to the strip.
+    pub fn update(&mut self) -> Result<(), AdiError> {
+        let mut colors: Vec<u32> = self
+            .buffer
+            .iter()
+            .map(|color| {
+                let color = u32::from(*color);
+                if self.gamma_correction {
+                    color
+                        .to_be_bytes()
+                        .map(|channel| GAMMA_TABLE[channel as usize])
+                        .into_iter()
+                        .fold(0, |color, channel| (color << 8) | channel as u32)
+                } else {
+                    color
+                }
+            })
+            .collect();
+
+        bail_on!(PROS_ERR, unsafe {
+            pros_sys::ext_adi_led_set(self.raw, colors.as_mut_ptr(), colors.len() as u32)
+        });
+        Ok(())
+    }
+}
+
+impl AdiDevice for AdiLed {
+    type PortIndexOutput = u8;
+
+    fn port_index(&self) -> Self::PortIndexOutput {
+        self.port.index()
+    }
+
+    fn expander_port_index(&self) -> Option<u8> {
+        self.port.expander_index()
+    }
+
+    fn device_type(&self) -> AdiDeviceType {
+        AdiDeviceType::DigitalOut
+    }
+}
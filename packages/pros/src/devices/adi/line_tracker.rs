@@ -0,0 +1,103 @@
+use pros_sys::PROS_ERR;
+
+use super::{AdiDevice, AdiDeviceType, AdiError, AdiPort};
+use crate::error::bail_on;
+
+/// The maximum value that can be read from the 12-bit ADC.
+const MAX_ANALOG_VALUE: u16 = 4095;
+
+/// A reflective line tracking sensor plugged into an [`AdiPort`].
+///
+/// Line trackers shine an infrared light onto a surface and measure how much is reflected back.
+/// Lighter surfaces reflect more light, which results in a *lower* raw reading.
+#[derive(Debug, Eq, PartialEq)]
+pub struct AdiLineTracker {
+    port: AdiPort,
+    white: u16,
+    black: u16,
+}
+
+impl AdiLineTracker {
+    /// Create a line tracker from an [`AdiPort`].
+    pub fn new(port: AdiPort) -> Self {
+        Self {
+            port,
+            white: 0,
+            black: MAX_ANALOG_VALUE,
+        }
+    }
+
+    /// Reads the raw 12-bit value from the sensor, ranging from 0 to 4095.
+    pub fn raw(&self) -> Result<u16, AdiError> {
+        Ok(bail_on!(PROS_ERR, unsafe {
+            pros_sys::ext_adi_analog_read(self.port.internal_expander_index(), self.port.index())
+        }) as u16)
+    }
+
+    /// Returns how reflective the surface under the sensor is, from 0.0 (black) to 1.0 (white).
+    ///
+    /// By default the full range of the sensor is used, but this can be narrowed
+    /// to the surfaces the sensor is actually used on with [`Self::calibrate_white`]
+    /// and [`Self::calibrate_black`].
+    pub fn reflectivity(&self) -> Result<f64, AdiError> {
+        let raw = self.raw()? as f64;
+        // The calibration setters guarantee that black reads higher than white.
+        let range = self.black as f64 - self.white as f64;
+        Ok((1.0 - (raw - self.white as f64) / range).clamp(0.0, 1.0))
+    }
+
+    /// Sets the white baseline by sampling the sensor while it is over a white surface.
+    ///
+    /// This computes an average from approximately 500 samples taken 1 ms apart, blocking for 0.5 s.
+    ///
+    /// Returns [`AdiError::InvalidCalibration`] and keeps the previous baseline if the
+    /// surface does not read lighter than the black baseline.
+    pub fn calibrate_white(&mut self) -> Result<(), AdiError> {
+        let white = self.sample_baseline()?;
+        if white >= self.black {
+            return Err(AdiError::InvalidCalibration);
+        }
+        self.white = white;
+        Ok(())
+    }
+
+    /// Sets the black baseline by sampling the sensor while it is over a black surface.
+    ///
+    /// This computes an average from approximately 500 samples taken 1 ms apart, blocking for 0.5 s.
+    ///
+    /// Returns [`AdiError::InvalidCalibration`] and keeps the previous baseline if the
+    /// surface does not read darker than the white baseline.
+    pub fn calibrate_black(&mut self) -> Result<(), AdiError> {
+        let black = self.sample_baseline()?;
+        if black <= self.white {
+            return Err(AdiError::InvalidCalibration);
+        }
+        self.black = black;
+        Ok(())
+    }
+
+    fn sample_baseline(&mut self) -> Result<u16, AdiError> {
+        Ok(bail_on!(PROS_ERR, unsafe {
+            pros_sys::ext_adi_analog_calibrate(
+                self.port.internal_expander_index(),
+                self.port.index(),
+            )
+        }) as u16)
+    }
+}
+
+impl AdiDevice for AdiLineTracker {
+    type PortIndexOutput = u8;
+
+    fn port_index(&self) -> Self::PortIndexOutput {
+        self.port.index()
+    }
+
+    fn expander_port_index(&self) -> Option<u8> {
+        self.port.expander_index()
+    }
+
+    fn device_type(&self) -> AdiDeviceType {
+        AdiDeviceType::AnalogIn
+    }
+}
@@ -9,16 +9,24 @@ pub mod analog;
 pub mod digital;
 pub mod encoder;
 pub mod gyro;
+pub mod led;
+pub mod line_tracker;
 pub mod motor;
+pub mod pneumatic;
 pub mod potentiometer;
+pub mod servo;
 pub mod ultrasonic;
 
 pub use analog::{AdiAnalogIn, AdiAnalogOut};
-pub use digital::{AdiDigitalIn, AdiDigitalOut};
+pub use digital::{AdiDigitalIn, AdiDigitalOut, Edge};
 pub use encoder::AdiEncoder;
 pub use gyro::AdiGyro;
+pub use led::AdiLed;
+pub use line_tracker::AdiLineTracker;
 pub use motor::AdiMotor;
+pub use pneumatic::{DoublePneumatic, Pneumatic};
 pub use potentiometer::AdiPotentiometer;
+pub use servo::AdiServo;
 pub use ultrasonic::AdiUltrasonic;
 
 /// Represents an ADI (three wire) port on a V5 Brain or V5 Three Wire Expander.
@@ -168,6 +176,14 @@ pub enum AdiError {
     #[snafu(display("ADI devices may only be initialized from one expander port."))]
     ExpanderPortMismatch,
 
+    #[snafu(display("The pixel index specified is outside of the LED strip."))]
+    InvalidPixelIndex,
+
+    #[snafu(display(
+        "The line tracker's black baseline must read higher than its white baseline."
+    ))]
+    InvalidCalibration,
+
     #[snafu(display("{source}"), context(false))]
     Port { source: PortError },
 }
@@ -0,0 +1,146 @@
+//! Pneumatic cylinders controlled by solenoid valves.
+//!
+//! A [`Pneumatic`] controls a single solenoid, which extends a single-acting cylinder
+//! or switches the air between the two ends of a double-acting cylinder.
+//! A [`DoublePneumatic`] controls a valve with a separate solenoid for each direction.
+//!
+//! ```ignore
+//! let mut clamp = Pneumatic::new(peripherals.adi_a);
+//! clamp.extend()?;
+//! ```
+
+use super::{digital::AdiDigitalOut, AdiDevice, AdiDeviceType, AdiError, AdiPort};
+
+/// A pneumatic cylinder controlled by a single solenoid.
+///
+/// The cylinder starts retracted.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Pneumatic {
+    solenoid: AdiDigitalOut,
+}
+
+impl Pneumatic {
+    /// Creates a pneumatic cylinder from the [`AdiPort`] its solenoid is plugged into.
+    pub fn new(port: AdiPort) -> Self {
+        Self {
+            solenoid: AdiDigitalOut::new(port),
+        }
+    }
+
+    /// Extends the cylinder.
+    pub fn extend(&mut self) -> Result<(), AdiError> {
+        self.solenoid.set_value(true)?;
+        Ok(())
+    }
+
+    /// Retracts the cylinder.
+    pub fn retract(&mut self) -> Result<(), AdiError> {
+        self.solenoid.set_value(false)?;
+        Ok(())
+    }
+
+    /// Extends the cylinder if it is retracted, or retracts it if it is extended.
+    pub fn toggle(&mut self) -> Result<(), AdiError> {
+        self.solenoid.toggle()
+    }
+
+    /// Returns `true` if the cylinder was last told to extend.
+    pub fn is_extended(&self) -> bool {
+        self.solenoid.value()
+    }
+}
+
+impl AdiDevice for Pneumatic {
+    type PortIndexOutput = u8;
+
+    fn port_index(&self) -> Self::PortIndexOutput {
+        self.solenoid.port_index()
+    }
+
+    fn expander_port_index(&self) -> Option<u8> {
+        self.solenoid.expander_port_index()
+    }
+
+    fn device_type(&self) -> AdiDeviceType {
+        AdiDeviceType::DigitalOut
+    }
+}
+
+/// A pneumatic cylinder controlled by a valve with one solenoid for each direction.
+///
+/// The cylinder starts with neither solenoid energized, so it stays wherever the air last moved it.
+#[derive(Debug, Eq, PartialEq)]
+pub struct DoublePneumatic {
+    extend_solenoid: AdiDigitalOut,
+    retract_solenoid: AdiDigitalOut,
+    extended: bool,
+}
+
+impl DoublePneumatic {
+    /// Creates a pneumatic cylinder from the [`AdiPort`]s its extend and retract solenoids are plugged into.
+    ///
+    /// If using a three wire expander, both ports must be on the same expander module.
+    pub fn new(extend_port: AdiPort, retract_port: AdiPort) -> Result<Self, AdiError> {
+        if extend_port.internal_expander_index() != retract_port.internal_expander_index() {
+            return Err(AdiError::ExpanderPortMismatch);
+        }
+
+        Ok(Self {
+            extend_solenoid: AdiDigitalOut::new(extend_port),
+            retract_solenoid: AdiDigitalOut::new(retract_port),
+            extended: false,
+        })
+    }
+
+    /// Extends the cylinder.
+    pub fn extend(&mut self) -> Result<(), AdiError> {
+        self.set_extended(true)
+    }
+
+    /// Retracts the cylinder.
+    pub fn retract(&mut self) -> Result<(), AdiError> {
+        self.set_extended(false)
+    }
+
+    /// Extends the cylinder if it is retracted, or retracts it if it is extended.
+    pub fn toggle(&mut self) -> Result<(), AdiError> {
+        self.set_extended(!self.extended)
+    }
+
+    /// Returns `true` if the cylinder was last told to extend.
+    pub fn is_extended(&self) -> bool {
+        self.extended
+    }
+
+    fn set_extended(&mut self, extended: bool) -> Result<(), AdiError> {
+        // Release the opposite solenoid first so that both are never energized at once.
+        if extended {
+            self.retract_solenoid.set_value(false)?;
+            self.extend_solenoid.set_value(true)?;
+        } else {
+            self.extend_solenoid.set_value(false)?;
+            self.retract_solenoid.set_value(true)?;
+        }
+        self.extended = extended;
+        Ok(())
+    }
+}
+
+impl AdiDevice for DoublePneumatic {
+    type PortIndexOutput = (u8, u8);
+
+    fn port_index(&self) -> Self::PortIndexOutput {
+        (
+            self.extend_solenoid.port_index(),
+            self.retract_solenoid.port_index(),
+        )
+    }
+
+    fn expander_port_index(&self) -> Option<u8> {
+        self.extend_solenoid.expander_port_index()
+    }
+
+    fn device_type(&self) -> AdiDeviceType {
+        AdiDeviceType::DigitalOut
+    }
+}
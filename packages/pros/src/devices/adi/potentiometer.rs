@@ -3,11 +3,12 @@ use pros_sys::{adi_potentiometer_type_e_t, ext_adi_potentiometer_t, PROS_ERR};
 use super::{AdiDevice, AdiDeviceType, AdiError, AdiPort};
 use crate::error::bail_on;
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, PartialEq)]
 pub struct AdiPotentiometer {
     potentiometer_type: AdiPotentiometerType,
     raw: ext_adi_potentiometer_t,
     port: AdiPort,
+    zero: f64,
 }
 
 impl AdiPotentiometer {
@@ -25,6 +26,7 @@ impl AdiPotentiometer {
             potentiometer_type,
             raw,
             port,
+            zero: 0.0,
         })
     }
 
@@ -32,7 +34,7 @@ impl AdiPotentiometer {
         self.potentiometer_type
     }
 
-    /// Gets the current potentiometer angle in tenths of a degree.
+    /// Gets the current potentiometer angle in degrees.
     ///
     /// The original potentiometer rotates 250 degrees
     /// thus returning an angle between 0-250 degrees.
@@ -43,6 +45,19 @@ impl AdiPotentiometer {
             pros_sys::ext_adi_potentiometer_get_angle(self.raw)
         }))
     }
+
+    /// Sets the current angle as the zero point for [`AdiPotentiometer::angle_from_zero`].
+    pub fn set_zero(&mut self) -> Result<(), AdiError> {
+        self.zero = self.angle()?;
+        Ok(())
+    }
+
+    /// Gets the angle in degrees relative to the zero point set by [`AdiPotentiometer::set_zero`].
+    ///
+    /// Angles on the opposite side of the zero point from the potentiometer's rotation are negative.
+    pub fn angle_from_zero(&self) -> Result<f64, AdiError> {
+        Ok(self.angle()? - self.zero)
+    }
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -0,0 +1,67 @@
+use pros_sys::PROS_ERR;
+
+use super::{AdiDevice, AdiDeviceType, AdiError, AdiPort};
+use crate::error::bail_on;
+
+/// The maximum raw PWM value that can be sent to a servo.
+const MAX_SERVO_VALUE: f64 = 127.0;
+
+/// A legacy hobby servo plugged into an [`AdiPort`].
+#[derive(Debug, Eq, PartialEq)]
+pub struct AdiServo {
+    port: AdiPort,
+}
+
+impl AdiServo {
+    /// Create a new servo from an [`AdiPort`].
+    pub fn new(port: AdiPort) -> Result<Self, AdiError> {
+        bail_on!(PROS_ERR, unsafe {
+            pros_sys::ext_adi_port_set_config(
+                port.internal_expander_index(),
+                port.index(),
+                pros_sys::E_ADI_LEGACY_SERVO,
+            )
+        });
+
+        Ok(Self { port })
+    }
+
+    /// Sets the target position of the servo from -1.0 to 1.0, which is mapped to the servo's full range of motion.
+    ///
+    /// Positions outside of this range are clamped.
+    pub fn set_position(&mut self, position: f64) -> Result<(), AdiError> {
+        let value = (position.clamp(-1.0, 1.0) * MAX_SERVO_VALUE) as i32;
+        bail_on!(PROS_ERR, unsafe {
+            pros_sys::ext_adi_port_set_value(
+                self.port.internal_expander_index(),
+                self.port.index(),
+                value,
+            )
+        });
+        Ok(())
+    }
+
+    /// Returns the last set target position of the servo from -1.0 to 1.0.
+    pub fn position(&self) -> Result<f64, AdiError> {
+        let value = bail_on!(PROS_ERR, unsafe {
+            pros_sys::ext_adi_port_get_value(self.port.internal_expander_index(), self.port.index())
+        });
+        Ok(value as f64 / MAX_SERVO_VALUE)
+    }
+}
+
+impl AdiDevice for AdiServo {
+    type PortIndexOutput = u8;
+
+    fn port_index(&self) -> Self::PortIndexOutput {
+        self.port.index()
+    }
+
+    fn expander_port_index(&self) -> Option<u8> {
+        self.port.expander_index()
+    }
+
+    fn device_type(&self) -> AdiDeviceType {
+        AdiDeviceType::LegacyServo
+    }
+}
@@ -36,11 +36,66 @@ impl AdiUltrasonic {
     }
 
     /// Gets the current ultrasonic sensor value in centimeters.
+    ///
+    /// If no object was found, zero is returned.
     pub fn value(&self) -> Result<i32, AdiError> {
         Ok(bail_on!(PROS_ERR, unsafe {
             pros_sys::ext_adi_ultrasonic_get(self.raw)
         }))
     }
+
+    /// Gets the distance to the nearest object detected by the sensor.
+    ///
+    /// If no echo was received, this will be a length of zero.
+    /// Use [`Self::distance_opt`] to handle that case explicitly.
+    pub fn distance(&self) -> Result<Length, AdiError> {
+        Ok(Length::from_cm(self.value()?.max(0) as f64))
+    }
+
+    /// Gets the distance to the nearest object detected by the sensor,
+    /// or `None` if no echo was received.
+    pub fn distance_opt(&self) -> Result<Option<Length>, AdiError> {
+        let value = self.value()?;
+        Ok((value > 0).then(|| Length::from_cm(value as f64)))
+    }
+}
+
+/// A distance measured by an [`AdiUltrasonic`] sensor.
+#[derive(Default, Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Length {
+    millimeters: f64,
+}
+
+impl Length {
+    /// Creates a length from millimeters.
+    pub const fn from_mm(millimeters: f64) -> Self {
+        Self { millimeters }
+    }
+
+    /// Creates a length from centimeters.
+    pub fn from_cm(centimeters: f64) -> Self {
+        Self::from_mm(centimeters * 10.0)
+    }
+
+    /// Creates a length from inches.
+    pub fn from_inches(inches: f64) -> Self {
+        Self::from_mm(inches * 25.4)
+    }
+
+    /// Returns the length in millimeters.
+    pub const fn as_mm(&self) -> f64 {
+        self.millimeters
+    }
+
+    /// Returns the length in centimeters.
+    pub fn as_cm(&self) -> f64 {
+        self.millimeters / 10.0
+    }
+
+    /// Returns the length in inches.
+    pub fn as_inches(&self) -> f64 {
+        self.millimeters / 25.4
+    }
 }
 
 impl AdiDevice for AdiUltrasonic {
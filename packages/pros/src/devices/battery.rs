@@ -5,28 +5,51 @@ use snafu::Snafu;
 
 use crate::error::{bail_on, map_errno};
 
-/// Get the robot's battery capacity.
+/// A snapshot of every reading from the robot's battery.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BatteryStatus {
+    /// Remaining capacity as a percentage.
+    pub capacity: f64,
+    /// Voltage in millivolts.
+    pub voltage: i32,
+    /// Current draw in milliamps.
+    pub current: i32,
+    /// Temperature in degrees Celsius.
+    pub temperature: f64,
+}
+
+/// Get every reading from the robot's battery at once.
+pub fn status() -> Result<BatteryStatus, BatteryError> {
+    Ok(BatteryStatus {
+        capacity: capacity()?,
+        voltage: voltage()?,
+        current: current()?,
+        temperature: temperature()?,
+    })
+}
+
+/// Get the robot's battery capacity as a percentage.
 pub fn capacity() -> Result<f64, BatteryError> {
     Ok(bail_on!(PROS_ERR_F, unsafe {
         pros_sys::misc::battery_get_capacity()
     }))
 }
 
-/// Get the current temperature of the robot's battery.
+/// Get the current temperature of the robot's battery in degrees Celsius.
 pub fn temperature() -> Result<f64, BatteryError> {
     Ok(bail_on!(PROS_ERR_F, unsafe {
         pros_sys::misc::battery_get_temperature()
     }))
 }
 
-/// Get the electric current of the robot's battery.
+/// Get the electric current of the robot's battery in milliamps.
 pub fn current() -> Result<i32, BatteryError> {
     Ok(bail_on!(PROS_ERR, unsafe {
         pros_sys::misc::battery_get_current()
     }))
 }
 
-/// Get the robot's battery voltage.
+/// Get the robot's battery voltage in millivolts.
 pub fn voltage() -> Result<i32, BatteryError> {
     Ok(bail_on!(PROS_ERR, unsafe {
         pros_sys::misc::battery_get_voltage()
@@ -1,6 +1,6 @@
 //! Read from the buttons and joysticks on the controller and write to the controller's display.
 //!
-//! Controllers are identified by their id, which is either 0 (master) or 1 (partner).
+//! Controllers are identified by their [`ControllerId`], which is either master or partner.
 //! State of a controller can be checked by calling [`Controller::state`] which will return a struct with all of the buttons' and joysticks' state.
 
 use alloc::{ffi::CString, vec::Vec};
@@ -27,6 +27,90 @@ pub struct Buttons {
     pub right_trigger_2: bool,
 }
 
+impl Buttons {
+    /// Returns whether the given button is pressed.
+    pub const fn is_pressed(&self, button: ControllerButton) -> bool {
+        match button {
+            ControllerButton::A => self.a,
+            ControllerButton::B => self.b,
+            ControllerButton::X => self.x,
+            ControllerButton::Y => self.y,
+            ControllerButton::Up => self.up,
+            ControllerButton::Down => self.down,
+            ControllerButton::Left => self.left,
+            ControllerButton::Right => self.right,
+            ControllerButton::LeftTrigger1 => self.left_trigger_1,
+            ControllerButton::LeftTrigger2 => self.left_trigger_2,
+            ControllerButton::RightTrigger1 => self.right_trigger_1,
+            ControllerButton::RightTrigger2 => self.right_trigger_2,
+        }
+    }
+}
+
+/// Detects when buttons on a controller are pressed or released.
+///
+/// [`ButtonTracker::update`] must be called exactly once per loop iteration,
+/// after which [`ButtonTracker::was_pressed`] and [`ButtonTracker::was_released`]
+/// report changes since the previous call to `update`.
+///
+/// ```rust
+/// # use pros::devices::controller::{ButtonTracker, Controller, ControllerButton, ControllerId};
+/// # fn example() -> Result<(), pros::devices::controller::ControllerError> {
+/// let mut buttons = ButtonTracker::new(Controller::new(ControllerId::Master));
+/// loop {
+///     buttons.update()?;
+///     if buttons.was_pressed(ControllerButton::A) {
+///         // Toggle something once per press.
+///     }
+///     pros::task::delay(core::time::Duration::from_millis(10));
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ButtonTracker {
+    controller: Controller,
+    previous: Buttons,
+    current: Buttons,
+}
+
+impl ButtonTracker {
+    /// Creates a new button tracker for the given controller.
+    ///
+    /// No buttons are considered pressed until the first call to [`ButtonTracker::update`].
+    pub fn new(controller: Controller) -> Self {
+        Self {
+            controller,
+            previous: Buttons::default(),
+            current: Buttons::default(),
+        }
+    }
+
+    /// Reads the current button states from the controller.
+    ///
+    /// This should be called once per loop iteration, before checking for presses or releases.
+    pub fn update(&mut self) -> Result<(), ControllerError> {
+        let buttons = self.controller.state()?.buttons;
+        self.previous = self.current;
+        self.current = buttons;
+        Ok(())
+    }
+
+    /// Returns the button states read by the last call to [`ButtonTracker::update`].
+    pub fn buttons(&self) -> Buttons {
+        self.current
+    }
+
+    /// Returns true if the button was pressed between the last two calls to [`ButtonTracker::update`].
+    pub fn was_pressed(&self, button: ControllerButton) -> bool {
+        self.current.is_pressed(button) && !self.previous.is_pressed(button)
+    }
+
+    /// Returns true if the button was released between the last two calls to [`ButtonTracker::update`].
+    pub fn was_released(&self, button: ControllerButton) -> bool {
+        !self.current.is_pressed(button) && self.previous.is_pressed(button)
+    }
+}
+
 /// Stores how far the joystick is away from the center (at *(0, 0)*) from -1 to 1.
 /// On the x axis left is negative, and right is positive.
 /// On the y axis down is negative, and up is positive.
@@ -58,19 +142,16 @@ pub struct ControllerLine {
 }
 
 impl ControllerLine {
-    pub const MAX_TEXT_LEN: usize = 14;
+    pub const MAX_TEXT_LEN: usize = 15;
     pub const MAX_LINE_NUM: u8 = 2;
     pub fn try_print(&self, text: impl Into<Vec<u8>>) -> Result<(), ControllerError> {
         let text = text.into();
-        let text_len = text.len();
-        assert!(
-            text_len > ControllerLine::MAX_TEXT_LEN,
-            "Printed text is too long to fit on controller display ({text_len} > {})",
-            Self::MAX_TEXT_LEN
-        );
-        let c_text = CString::new(text).expect("parameter `text` should not contain null bytes");
+        if text.len() > Self::MAX_TEXT_LEN {
+            return Err(ControllerError::TextTooLong);
+        }
+        let c_text = CString::new(text).map_err(|_| ControllerError::InvalidText)?;
         bail_on!(PROS_ERR, unsafe {
-            pros_sys::controller_set_text(self.controller.id(), self.line, 0, c_text.as_ptr())
+            pros_sys::controller_set_text(self.controller.raw_id(), self.line, 0, c_text.as_ptr())
         });
         Ok(())
     }
@@ -107,23 +188,50 @@ pub enum JoystickAxis {
     RightY = pros_sys::E_CONTROLLER_ANALOG_RIGHT_Y,
 }
 
-/// The basic type for a controller.
-/// Used to get the state of its joysticks and controllers.
+/// A joystick on the VEX controller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stick {
+    Left,
+    Right,
+}
+
+/// Which controller connected to the brain a [`Controller`] reads from.
 #[repr(u32)]
-#[derive(Debug, Clone, Copy)]
-pub enum Controller {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ControllerId {
     Master = pros_sys::E_CONTROLLER_MASTER,
     Partner = pros_sys::E_CONTROLLER_PARTNER,
 }
 
+/// The basic type for a controller.
+/// Used to get the state of its joysticks and controllers.
+#[derive(Debug, Clone, Copy)]
+pub struct Controller {
+    id: ControllerId,
+    deadband: f64,
+}
+
 impl Controller {
-    fn id(&self) -> controller_id_e_t {
-        *self as controller_id_e_t
+    /// The maximum number of characters in a rumble pattern.
+    pub const MAX_RUMBLE_LEN: usize = 8;
+
+    /// Creates a controller with no deadband.
+    pub const fn new(id: ControllerId) -> Self {
+        Self { id, deadband: 0.0 }
+    }
+
+    /// Returns which controller this reads from.
+    pub const fn id(&self) -> ControllerId {
+        self.id
+    }
+
+    fn raw_id(&self) -> controller_id_e_t {
+        self.id as controller_id_e_t
     }
 
     pub fn line(&self, line_num: u8) -> ControllerLine {
         assert!(
-            line_num > ControllerLine::MAX_LINE_NUM,
+            line_num <= ControllerLine::MAX_LINE_NUM,
             "Line number is too large for controller display ({line_num} > {})",
             ControllerLine::MAX_LINE_NUM
         );
@@ -143,7 +251,7 @@ impl Controller {
                         x: bail_on!(
                             PROS_ERR,
                             pros_sys::controller_get_analog(
-                                self.id(),
+                                self.raw_id(),
                                 pros_sys::E_CONTROLLER_ANALOG_LEFT_X,
                             )
                         ) as f32
@@ -151,7 +259,7 @@ impl Controller {
                         y: bail_on!(
                             PROS_ERR,
                             pros_sys::controller_get_analog(
-                                self.id(),
+                                self.raw_id(),
                                 pros_sys::E_CONTROLLER_ANALOG_LEFT_Y,
                             )
                         ) as f32
@@ -161,7 +269,7 @@ impl Controller {
                         x: bail_on!(
                             PROS_ERR,
                             pros_sys::controller_get_analog(
-                                self.id(),
+                                self.raw_id(),
                                 pros_sys::E_CONTROLLER_ANALOG_RIGHT_X,
                             )
                         ) as f32
@@ -169,7 +277,7 @@ impl Controller {
                         y: bail_on!(
                             PROS_ERR,
                             pros_sys::controller_get_analog(
-                                self.id(),
+                                self.raw_id(),
                                 pros_sys::E_CONTROLLER_ANALOG_RIGHT_Y,
                             )
                         ) as f32
@@ -182,84 +290,84 @@ impl Controller {
                     a: bail_on!(
                         PROS_ERR,
                         pros_sys::controller_get_digital(
-                            self.id(),
+                            self.raw_id(),
                             pros_sys::E_CONTROLLER_DIGITAL_A,
                         )
                     ) == 1,
                     b: bail_on!(
                         PROS_ERR,
                         pros_sys::controller_get_digital(
-                            self.id(),
+                            self.raw_id(),
                             pros_sys::E_CONTROLLER_DIGITAL_B,
                         )
                     ) == 1,
                     x: bail_on!(
                         PROS_ERR,
                         pros_sys::controller_get_digital(
-                            self.id(),
+                            self.raw_id(),
                             pros_sys::E_CONTROLLER_DIGITAL_X,
                         )
                     ) == 1,
                     y: bail_on!(
                         PROS_ERR,
                         pros_sys::controller_get_digital(
-                            self.id(),
+                            self.raw_id(),
                             pros_sys::E_CONTROLLER_DIGITAL_Y,
                         )
                     ) == 1,
                     up: bail_on!(
                         PROS_ERR,
                         pros_sys::controller_get_digital(
-                            self.id(),
+                            self.raw_id(),
                             pros_sys::E_CONTROLLER_DIGITAL_UP,
                         )
                     ) == 1,
                     down: bail_on!(
                         PROS_ERR,
                         pros_sys::controller_get_digital(
-                            self.id(),
+                            self.raw_id(),
                             pros_sys::E_CONTROLLER_DIGITAL_DOWN,
                         )
                     ) == 1,
                     left: bail_on!(
                         PROS_ERR,
                         pros_sys::controller_get_digital(
-                            self.id(),
+                            self.raw_id(),
                             pros_sys::E_CONTROLLER_DIGITAL_LEFT,
                         )
                     ) == 1,
                     right: bail_on!(
                         PROS_ERR,
                         pros_sys::controller_get_digital(
-                            self.id(),
+                            self.raw_id(),
                             pros_sys::E_CONTROLLER_DIGITAL_RIGHT,
                         )
                     ) == 1,
                     left_trigger_1: bail_on!(
                         PROS_ERR,
                         pros_sys::controller_get_digital(
-                            self.id(),
+                            self.raw_id(),
                             pros_sys::E_CONTROLLER_DIGITAL_L1,
                         )
                     ) == 1,
                     left_trigger_2: bail_on!(
                         PROS_ERR,
                         pros_sys::controller_get_digital(
-                            self.id(),
+                            self.raw_id(),
                             pros_sys::E_CONTROLLER_DIGITAL_L2,
                         )
                     ) == 1,
                     right_trigger_1: bail_on!(
                         PROS_ERR,
                         pros_sys::controller_get_digital(
-                            self.id(),
+                            self.raw_id(),
                             pros_sys::E_CONTROLLER_DIGITAL_R1,
                         )
                     ) == 1,
                     right_trigger_2: bail_on!(
                         PROS_ERR,
                         pros_sys::controller_get_digital(
-                            self.id(),
+                            self.raw_id(),
                             pros_sys::E_CONTROLLER_DIGITAL_R2,
                         )
                     ) == 1,
@@ -271,17 +379,142 @@ impl Controller {
     /// Gets the state of a specific button on the controller.
     pub fn button(&self, button: ControllerButton) -> Result<bool, ControllerError> {
         Ok(bail_on!(PROS_ERR, unsafe {
-            pros_sys::controller_get_digital(self.id(), button as pros_sys::controller_digital_e_t)
+            pros_sys::controller_get_digital(
+                self.raw_id(),
+                button as pros_sys::controller_digital_e_t,
+            )
         }) == 1)
     }
 
     /// Gets the state of a specific joystick axis on the controller.
     pub fn joystick_axis(&self, axis: JoystickAxis) -> Result<f32, ControllerError> {
         Ok(bail_on!(PROS_ERR, unsafe {
-            pros_sys::controller_get_analog(self.id(), axis as pros_sys::controller_analog_e_t)
+            pros_sys::controller_get_analog(self.raw_id(), axis as pros_sys::controller_analog_e_t)
         }) as f32
             / 127.0)
     }
+
+    /// Checks if the controller is connected to the brain.
+    pub fn is_connected(&self) -> Result<bool, ControllerError> {
+        Ok(bail_on!(PROS_ERR, unsafe {
+            pros_sys::controller_is_connected(self.raw_id())
+        }) == 1)
+    }
+
+    /// Gets the battery capacity of the controller.
+    pub fn battery_capacity(&self) -> Result<i32, ControllerError> {
+        Ok(bail_on!(PROS_ERR, unsafe {
+            pros_sys::controller_get_battery_capacity(self.raw_id())
+        }))
+    }
+
+    /// Gets the battery level of the controller.
+    pub fn battery_level(&self) -> Result<i32, ControllerError> {
+        Ok(bail_on!(PROS_ERR, unsafe {
+            pros_sys::controller_get_battery_level(self.raw_id())
+        }))
+    }
+
+    /// Sets the deadband used by [`Controller::stick`].
+    ///
+    /// Any axis closer to the center than `deadband` is read as zero. Joysticks rarely rest at
+    /// exactly *(0, 0)*, so a small deadband (around 0.05) keeps motors from creeping when the
+    /// driver isn't touching the stick. Controllers start with no deadband.
+    pub fn set_deadband(&mut self, deadband: f64) {
+        self.deadband = deadband;
+    }
+
+    /// Returns the deadband set by [`Controller::set_deadband`].
+    pub fn stick_deadband(&self) -> f64 {
+        self.deadband
+    }
+
+    /// Gets the position of one joystick on the controller as an *(x, y)* pair,
+    /// with the controller's [deadband](Controller::set_deadband) applied.
+    ///
+    /// Both axes are scaled from -1 to 1.
+    pub fn stick(&self, stick: Stick) -> Result<(f64, f64), ControllerError> {
+        let (x, y) = match stick {
+            Stick::Left => (JoystickAxis::LeftX, JoystickAxis::LeftY),
+            Stick::Right => (JoystickAxis::RightX, JoystickAxis::RightY),
+        };
+        let read = |axis| -> Result<f64, ControllerError> {
+            let value = self.joystick_axis(axis)? as f64;
+            Ok(if value.abs() < self.deadband {
+                0.0
+            } else {
+                value
+            })
+        };
+        Ok((read(x)?, read(y)?))
+    }
+
+    /// Prints text to the controller screen, starting at the given line and column.
+    ///
+    /// The screen has 3 lines of 15 columns each.
+    /// The controller only accepts a new screen update every 50ms, so updates sent faster than that may be dropped.
+    pub fn print(&self, line: u8, col: u8, text: &str) -> Result<(), ControllerError> {
+        if line > ControllerLine::MAX_LINE_NUM {
+            return Err(ControllerError::InvalidLine);
+        }
+        if col as usize + text.len() > ControllerLine::MAX_TEXT_LEN {
+            return Err(ControllerError::TextTooLong);
+        }
+
+        let c_text = CString::new(text).map_err(|_| ControllerError::InvalidText)?;
+        bail_on!(PROS_ERR, unsafe {
+            pros_sys::controller_set_text(self.raw_id(), line, col, c_text.as_ptr())
+        });
+        Ok(())
+    }
+
+    /// Clears a single line of the controller screen.
+    ///
+    /// This is subject to the same 50ms update limit as [`Controller::print`].
+    pub fn clear_line(&self, line: u8) -> Result<(), ControllerError> {
+        if line > ControllerLine::MAX_LINE_NUM {
+            return Err(ControllerError::InvalidLine);
+        }
+
+        bail_on!(PROS_ERR, unsafe {
+            pros_sys::controller_clear_line(self.raw_id(), line)
+        });
+        Ok(())
+    }
+
+    /// Clears every line of the controller screen.
+    ///
+    /// This is subject to the same 50ms update limit as [`Controller::print`].
+    pub fn clear(&self) -> Result<(), ControllerError> {
+        bail_on!(PROS_ERR, unsafe {
+            pros_sys::controller_clear(self.raw_id())
+        });
+        Ok(())
+    }
+
+    /// Rumbles the controller using a pattern of dots, dashes, and spaces.
+    ///
+    /// Dots are short rumbles, dashes are long rumbles, and spaces are pauses.
+    /// The pattern can be at most [`Controller::MAX_RUMBLE_LEN`] characters long.
+    ///
+    /// ```rust
+    /// # use pros::devices::controller::{Controller, ControllerId};
+    /// Controller::new(ControllerId::Master).rumble(". - .")?;
+    /// # Ok::<(), pros::devices::controller::ControllerError>(())
+    /// ```
+    pub fn rumble(&self, pattern: &str) -> Result<(), ControllerError> {
+        if pattern.len() > Self::MAX_RUMBLE_LEN
+            || !pattern.chars().all(|c| matches!(c, '.' | '-' | ' '))
+        {
+            return Err(ControllerError::InvalidRumblePattern);
+        }
+
+        let c_pattern = CString::new(pattern).unwrap();
+        bail_on!(PROS_ERR, unsafe {
+            pros_sys::controller_rumble(self.raw_id(), c_pattern.as_ptr())
+        });
+        Ok(())
+    }
 }
 
 #[derive(Debug, Snafu)]
@@ -293,6 +526,20 @@ pub enum ControllerError {
 
     #[snafu(display("Another resource is already using the controller"))]
     ConcurrentAccess,
+
+    #[snafu(display(
+        "The rumble pattern must be at most 8 characters long and only contain '.', '-', or ' '."
+    ))]
+    InvalidRumblePattern,
+
+    #[snafu(display("The controller screen only has lines 0 through 2."))]
+    InvalidLine,
+
+    #[snafu(display("The text does not fit on the controller screen."))]
+    TextTooLong,
+
+    #[snafu(display("The text contains a null byte."))]
+    InvalidText,
 }
 
 map_errno! {
@@ -16,14 +16,20 @@
 //! - [`devices::battery`] provides functions for getting information about the currently connected
 //!   battery.
 //! - [`devices::controller`] provides types for interacting with the V5 controller.
+//! - [`devices::odometry`] tracks the position of the robot using tracking wheels.
 
 pub mod adi;
 pub mod smart;
 
 pub mod battery;
 pub mod controller;
+pub mod odometry;
 pub mod peripherals;
 pub mod position;
+mod retry;
+pub mod velocity;
 
 pub use controller::Controller;
 pub use position::Position;
+pub use retry::{retry, retry_async, TransientError};
+pub use velocity::Velocity;
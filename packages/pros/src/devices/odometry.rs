@@ -0,0 +1,270 @@
+//! Wheel odometry.
+//!
+//! [`Odometry`] tracks the position of a robot on the field by measuring how far
+//! [`TrackingWheel`]s have travelled, optionally using an [`InertialSensor`] for the robot's heading.
+//!
+//! Poses use a coordinate system where the positive x axis points in the direction the robot
+//! faced when tracking started, the positive y axis points to its left, and headings are
+//! measured in radians counterclockwise from the x axis. Distances use the same units as the
+//! wheel diameters passed to [`TrackingWheel::new`].
+//!
+//! ```ignore
+//! let left = TrackingWheel::new(RotationSensor::new(peripherals.port_1, false)?, 2.75, 1.0, 5.0);
+//! let right = TrackingWheel::new(RotationSensor::new(peripherals.port_2, true)?, 2.75, 1.0, -5.0);
+//! let mut odometry = Odometry::new(left, right)?;
+//!
+//! loop {
+//!     odometry.update()?;
+//!     println!("{:?}", odometry.pose());
+//!     task::delay(Duration::from_millis(10));
+//! }
+//! ```
+
+use core::f64::consts::PI;
+
+use snafu::Snafu;
+
+use super::{
+    adi::{AdiEncoder, AdiError},
+    smart::{imu::InertialError, InertialSensor, RotationSensor},
+    Position,
+};
+use crate::error::PortError;
+
+/// The number of ticks in one rotation of an [`AdiEncoder`].
+const ADI_ENCODER_TICKS_PER_ROTATION: f64 = 360.0;
+
+/// A sensor that can measure the rotation of a [`TrackingWheel`].
+#[derive(Debug, PartialEq)]
+pub enum TrackingSensor {
+    /// A V5 rotation sensor.
+    Rotation(RotationSensor),
+    /// A legacy ADI quadrature encoder.
+    Encoder(AdiEncoder),
+}
+
+impl TrackingSensor {
+    /// Returns the total rotation measured by the sensor.
+    pub fn position(&self) -> Result<Position, OdometryError> {
+        Ok(match self {
+            Self::Rotation(sensor) => sensor.position()?,
+            Self::Encoder(encoder) => {
+                Position::from_rotations(encoder.value()? as f64 / ADI_ENCODER_TICKS_PER_ROTATION)
+            }
+        })
+    }
+}
+
+impl From<RotationSensor> for TrackingSensor {
+    fn from(sensor: RotationSensor) -> Self {
+        Self::Rotation(sensor)
+    }
+}
+
+impl From<AdiEncoder> for TrackingSensor {
+    fn from(encoder: AdiEncoder) -> Self {
+        Self::Encoder(encoder)
+    }
+}
+
+/// An unpowered wheel used to measure the distance a robot has travelled.
+#[derive(Debug, PartialEq)]
+pub struct TrackingWheel {
+    sensor: TrackingSensor,
+    wheel_diameter: f64,
+    gear_ratio: f64,
+    offset: f64,
+}
+
+impl TrackingWheel {
+    /// Creates a new tracking wheel.
+    ///
+    /// - `gear_ratio` is the number of wheel rotations per sensor rotation.
+    /// - `offset` is the distance from the wheel to the robot's center of rotation,
+    ///   perpendicular to the direction the wheel rolls. For wheels parallel to the robot's
+    ///   direction of travel this is positive to the left of the center, and for wheels
+    ///   perpendicular to it this is positive in front of the center.
+    pub fn new(
+        sensor: impl Into<TrackingSensor>,
+        wheel_diameter: f64,
+        gear_ratio: f64,
+        offset: f64,
+    ) -> Self {
+        Self {
+            sensor: sensor.into(),
+            wheel_diameter,
+            gear_ratio,
+            offset,
+        }
+    }
+
+    /// Returns the total distance the wheel has travelled.
+    pub fn distance(&self) -> Result<f64, OdometryError> {
+        Ok(self.sensor.position()?.into_rotations() * self.gear_ratio * PI * self.wheel_diameter)
+    }
+
+    /// Returns the distance from the wheel to the robot's center of rotation.
+    pub fn offset(&self) -> f64 {
+        self.offset
+    }
+}
+
+/// The position and heading of a robot on the field.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Pose {
+    /// The distance along the x axis.
+    pub x: f64,
+    /// The distance along the y axis.
+    pub y: f64,
+    /// The heading in radians, counterclockwise from the x axis.
+    pub heading: f64,
+}
+
+/// Tracks the pose of a robot using tracking wheels.
+///
+/// Two parallel tracking wheels are required. A third, perpendicular wheel can be added with
+/// [`Odometry::with_horizontal_wheel`] to track sideways movement, and an [`InertialSensor`] can be
+/// added with [`Odometry::with_imu`] to measure heading more accurately than the parallel wheels can.
+#[derive(Debug)]
+pub struct Odometry {
+    left: TrackingWheel,
+    right: TrackingWheel,
+    horizontal: Option<TrackingWheel>,
+    imu: Option<InertialSensor>,
+
+    pose: Pose,
+    last_left: f64,
+    last_right: f64,
+    last_horizontal: f64,
+    /// The IMU's counterclockwise rotation in radians when the heading was last set.
+    imu_origin: f64,
+    /// The heading that the IMU's rotation is measured relative to.
+    imu_heading_origin: f64,
+}
+
+impl Odometry {
+    /// Creates a new odometry tracker from two tracking wheels parallel to the robot's direction of travel.
+    ///
+    /// The robot starts at the origin with a heading of zero.
+    ///
+    /// Returns [`OdometryError::InvalidGeometry`] if both wheels have the same offset,
+    /// since the robot's heading cannot be measured from them.
+    pub fn new(left: TrackingWheel, right: TrackingWheel) -> Result<Self, OdometryError> {
+        if left.offset == right.offset {
+            return Err(OdometryError::InvalidGeometry);
+        }
+
+        Ok(Self {
+            last_left: left.distance()?,
+            last_right: right.distance()?,
+            left,
+            right,
+            horizontal: None,
+            imu: None,
+            pose: Pose::default(),
+            last_horizontal: 0.0,
+            imu_origin: 0.0,
+            imu_heading_origin: 0.0,
+        })
+    }
+
+    /// Adds a tracking wheel perpendicular to the robot's direction of travel, used to measure sideways movement.
+    pub fn with_horizontal_wheel(mut self, wheel: TrackingWheel) -> Result<Self, OdometryError> {
+        self.last_horizontal = wheel.distance()?;
+        self.horizontal = Some(wheel);
+        Ok(self)
+    }
+
+    /// Uses an inertial sensor to measure the robot's heading instead of the parallel tracking wheels.
+    ///
+    /// The inertial sensor should already be calibrated.
+    pub fn with_imu(mut self, imu: InertialSensor) -> Result<Self, OdometryError> {
+        self.imu_origin = imu_rotation(&imu)?;
+        self.imu_heading_origin = self.pose.heading;
+        self.imu = Some(imu);
+        Ok(self)
+    }
+
+    /// Returns the current pose of the robot.
+    pub fn pose(&self) -> Pose {
+        self.pose
+    }
+
+    /// Sets the current pose of the robot.
+    pub fn set_pose(&mut self, pose: Pose) -> Result<(), OdometryError> {
+        if let Some(imu) = &self.imu {
+            self.imu_origin = imu_rotation(imu)?;
+            self.imu_heading_origin = pose.heading;
+        }
+        self.pose = pose;
+        Ok(())
+    }
+
+    /// Reads the tracking wheels and updates the robot's pose.
+    ///
+    /// This should be called frequently, since the pose is only accurate if the robot
+    /// travels along a circular arc between updates.
+    pub fn update(&mut self) -> Result<(), OdometryError> {
+        let left = self.left.distance()?;
+        let right = self.right.distance()?;
+        let delta_left = left - self.last_left;
+        let delta_right = right - self.last_right;
+        self.last_left = left;
+        self.last_right = right;
+
+        let delta_heading = match &self.imu {
+            Some(imu) => {
+                self.imu_heading_origin + (imu_rotation(imu)? - self.imu_origin) - self.pose.heading
+            }
+            None => (delta_right - delta_left) / (self.left.offset - self.right.offset),
+        };
+
+        // Movement of the center of rotation in the robot's frame of reference.
+        let mut forward = (delta_left
+            + self.left.offset * delta_heading
+            + delta_right
+            + self.right.offset * delta_heading)
+            / 2.0;
+        let mut sideways = match &self.horizontal {
+            Some(horizontal) => {
+                let distance = horizontal.distance()?;
+                let delta = distance - self.last_horizontal;
+                self.last_horizontal = distance;
+                delta - horizontal.offset * delta_heading
+            }
+            None => 0.0,
+        };
+
+        // The robot travelled along an arc rather than a straight line, so the chord is shorter.
+        if delta_heading != 0.0 {
+            let chord_scale = 2.0 * libm::sin(delta_heading / 2.0) / delta_heading;
+            forward *= chord_scale;
+            sideways *= chord_scale;
+        }
+
+        let average_heading = self.pose.heading + delta_heading / 2.0;
+        let (sin, cos) = (libm::sin(average_heading), libm::cos(average_heading));
+        self.pose.x += forward * cos - sideways * sin;
+        self.pose.y += forward * sin + sideways * cos;
+        self.pose.heading += delta_heading;
+
+        Ok(())
+    }
+}
+
+/// Returns the inertial sensor's rotation in radians counterclockwise.
+fn imu_rotation(imu: &InertialSensor) -> Result<f64, OdometryError> {
+    Ok(-imu.rotation()? * PI / 180.0)
+}
+
+#[derive(Debug, Snafu)]
+pub enum OdometryError {
+    #[snafu(display("Parallel tracking wheels must have different offsets."))]
+    InvalidGeometry,
+    #[snafu(display("{source}"), context(false))]
+    Port { source: PortError },
+    #[snafu(display("{source}"), context(false))]
+    Adi { source: AdiError },
+    #[snafu(display("{source}"), context(false))]
+    Inertial { source: InertialError },
+}
@@ -1,9 +1,33 @@
+//! Peripheral ownership.
+//!
+//! Every smart and ADI port on the brain is represented by a single [`SmartPort`] or [`AdiPort`].
+//! Ports cannot be copied or cloned, and devices take ownership of the port they are created on.
+//! Because of this, the borrow checker guarantees that a port is only ever used by one device:
+//!
+//! ```ignore
+//! # use pros::prelude::*;
+//! let peripherals = Peripherals::take().unwrap();
+//! let left = Motor::new(peripherals.port_1, BrakeMode::Brake).unwrap();
+//! // error[E0382]: use of moved value: `peripherals.port_1`
+//! let right = Motor::new(peripherals.port_1, BrakeMode::Brake).unwrap();
+//! ```
+//!
+//! [`Peripherals::take`] only succeeds once, so there is only ever one set of ports.
+//! When ports need to be chosen at runtime, [`DynamicPeripherals`] performs the same check
+//! at runtime instead, returning `None` if a port has already been taken.
+//!
+//! The only ways to get around this are the `unsafe` functions [`Peripherals::steal`],
+//! [`SmartPort::new`], and [`AdiPort::new`].
+
 use core::sync::atomic::AtomicBool;
 
 use crate::devices::{adi::AdiPort, smart::SmartPort};
 
 static PERIPHERALS_TAKEN: AtomicBool = AtomicBool::new(false);
 
+/// A unique set of every smart and ADI port on the brain.
+///
+/// See the [module-level documentation](self) for how this prevents devices from sharing a port.
 pub struct Peripherals {
     pub port_1: SmartPort,
     pub port_2: SmartPort,
@@ -73,6 +97,9 @@ impl Peripherals {
         }
     }
 
+    /// Takes the peripherals.
+    ///
+    /// This returns `None` if the peripherals have already been taken.
     pub fn take() -> Option<Self> {
         if PERIPHERALS_TAKEN.swap(true, core::sync::atomic::Ordering::AcqRel) {
             None
@@ -81,6 +108,14 @@ impl Peripherals {
         }
     }
 
+    /// Takes the peripherals, even if they have already been taken.
+    ///
+    /// # Safety
+    ///
+    /// Creating new ports is inherently unsafe due to the possibility of constructing
+    /// more than one device on the same port index allowing multiple mutable references to
+    /// the same hardware device. The caller must ensure that no port taken from a previous
+    /// [`Peripherals`] is used to create a device on the same port as one taken from this one.
     pub unsafe fn steal() -> Self {
         PERIPHERALS_TAKEN.store(true, core::sync::atomic::Ordering::Release);
         Self::new()
@@ -135,9 +170,32 @@ impl DynamicPeripherals {
         if self.adi_slots[port_index] {
             return None;
         }
-        self.smart_ports[port_index] = true;
+        self.adi_slots[port_index] = true;
         Some(unsafe { AdiPort::new(port_index as u8 + 1, None) })
     }
+
+    /// Returns a [`SmartPort`] so that it can be taken again with [`DynamicPeripherals::take_smart_port`].
+    ///
+    /// The port is taken by value to guarantee that no device is still using it.
+    pub fn release_smart_port(&mut self, port: SmartPort) {
+        self.smart_ports[port.index() as usize - 1] = false;
+    }
+
+    /// Returns an [`AdiPort`] so that it can be taken again with [`DynamicPeripherals::take_adi_port`].
+    ///
+    /// The port is taken by value to guarantee that no device is still using it.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the port belongs to an ADI expander,
+    /// since those ports are not managed by [`DynamicPeripherals`].
+    pub fn release_adi_port(&mut self, port: AdiPort) {
+        assert!(
+            port.expander_index().is_none(),
+            "ADI expander ports cannot be released to DynamicPeripherals"
+        );
+        self.adi_slots[port.index() as usize - 1] = false;
+    }
 }
 impl From<Peripherals> for DynamicPeripherals {
     fn from(peripherals: Peripherals) -> Self {
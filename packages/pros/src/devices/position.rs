@@ -2,15 +2,21 @@
 //!
 //! Positions have many conversion functions as well as common operator implementations for ease of use.
 
-use core::{cmp::Ordering, ops::*};
+use core::{cmp::Ordering, f64::consts::PI, ops::*};
+
+/// The number of encoder counts in one rotation.
+const COUNTS_PER_ROTATION: f64 = 4096.0;
 
-//TODO: Add more unit types to this.
 /// Represents an angular position.
+///
+/// ```ignore
+/// let target = motor.position()? + Position::from_degrees(90.0);
+/// ```
 #[derive(Clone, Copy, Debug)]
 pub enum Position {
     Degrees(f64),
     Rotations(f64),
-    /// Raw encoder ticks.
+    /// Raw encoder ticks, with 4096 ticks per rotation.
     Counts(i64),
 }
 
@@ -30,12 +36,17 @@ impl Position {
         Self::Counts(position)
     }
 
+    /// Creates a position from a specified number of radians.
+    pub fn from_radians(position: f64) -> Self {
+        Self::Degrees(position * 180.0 / PI)
+    }
+
     /// Converts a position into degrees.
     pub fn into_degrees(self) -> f64 {
         match self {
             Self::Degrees(num) => num,
             Self::Rotations(num) => num * 360.0,
-            Self::Counts(num) => num as f64 * (360.0 / 4096.0),
+            Self::Counts(num) => num as f64 * (360.0 / COUNTS_PER_ROTATION),
         }
     }
 
@@ -44,15 +55,20 @@ impl Position {
         match self {
             Self::Degrees(num) => num / 360.0,
             Self::Rotations(num) => num,
-            Self::Counts(num) => num as f64 * 4096.0,
+            Self::Counts(num) => num as f64 / COUNTS_PER_ROTATION,
         }
     }
 
+    /// Converts a position into radians.
+    pub fn into_radians(self) -> f64 {
+        self.into_degrees() * PI / 180.0
+    }
+
     /// Converts a position into counts (raw encoder ticks).
     pub fn into_counts(self) -> i64 {
         match self {
-            Self::Degrees(num) => (num * 4096.0 / 360.0) as i64,
-            Self::Rotations(num) => (num * 4096.0) as i64,
+            Self::Degrees(num) => (num * COUNTS_PER_ROTATION / 360.0) as i64,
+            Self::Rotations(num) => (num * COUNTS_PER_ROTATION) as i64,
             Self::Counts(num) => num,
         }
     }
@@ -100,6 +116,20 @@ impl MulAssign<Self> for Position {
     }
 }
 
+impl Mul<f64> for Position {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::from_degrees(self.into_degrees() * rhs)
+    }
+}
+
+impl MulAssign<f64> for Position {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs;
+    }
+}
+
 impl Div<Self> for Position {
     type Output = Self;
 
@@ -114,6 +144,20 @@ impl DivAssign<Self> for Position {
     }
 }
 
+impl Div<f64> for Position {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        Self::from_degrees(self.into_degrees() / rhs)
+    }
+}
+
+impl DivAssign<f64> for Position {
+    fn div_assign(&mut self, rhs: f64) {
+        *self = *self / rhs;
+    }
+}
+
 impl Rem<Self> for Position {
     type Output = Self;
 
@@ -0,0 +1,93 @@
+//! Retrying operations that fail because of transient device errors.
+
+use core::{future::Future, time::Duration};
+
+use crate::{
+    devices::{
+        adi::AdiError,
+        smart::{
+            gps::GpsError, imu::InertialError, link::LinkError, motor::MotorError,
+            optical::OpticalError, serial::SerialError, vision::VisionError,
+        },
+    },
+    error::PortError,
+};
+
+/// An error that may not occur again if the operation that caused it is retried.
+pub trait TransientError {
+    /// Returns `true` if retrying the operation may succeed.
+    fn is_transient(&self) -> bool;
+}
+
+impl TransientError for PortError {
+    fn is_transient(&self) -> bool {
+        matches!(self, Self::PortNotConnected)
+    }
+}
+
+/// Implements [`TransientError`] for error types with a `Port { source: PortError }` variant.
+macro_rules! impl_transient_error {
+    ($($err_ty:ty),* $(,)?) => {
+        $(
+            impl TransientError for $err_ty {
+                fn is_transient(&self) -> bool {
+                    matches!(self, Self::Port { source } if source.is_transient())
+                }
+            }
+        )*
+    };
+}
+
+impl_transient_error!(
+    AdiError,
+    GpsError,
+    InertialError,
+    LinkError,
+    MotorError,
+    OpticalError,
+    SerialError,
+    VisionError,
+);
+
+/// Runs `f` until it succeeds, it returns an error that is not transient,
+/// or it has been run `attempts` times.
+///
+/// `f` is always run at least once.
+///
+/// ```ignore
+/// let distance = retry(3, || sensor.distance())?;
+/// ```
+pub fn retry<T, E, F>(attempts: usize, mut f: F) -> Result<T, E>
+where
+    E: TransientError,
+    F: FnMut() -> Result<T, E>,
+{
+    let mut remaining = attempts.max(1);
+    loop {
+        remaining -= 1;
+        match f() {
+            Err(err) if err.is_transient() && remaining > 0 => {}
+            result => return result,
+        }
+    }
+}
+
+/// Runs the future returned by `f` until it succeeds, it returns an error that is not transient,
+/// or it has been run `attempts` times, sleeping for `delay` between attempts.
+///
+/// `f` is always run at least once.
+pub async fn retry_async<T, E, F, Fut>(attempts: usize, delay: Duration, mut f: F) -> Result<T, E>
+where
+    E: TransientError,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let mut remaining = attempts.max(1);
+    loop {
+        remaining -= 1;
+        match f().await {
+            Err(err) if err.is_transient() && remaining > 0 => crate::task::sleep(delay).await,
+            result => return result,
+        }
+    }
+}
@@ -9,6 +9,19 @@ use pros_sys::PROS_ERR;
 use super::{SmartDevice, SmartDeviceType, SmartPort};
 use crate::error::{bail_on, PortError};
 
+/// Represents all of the data output from a distance sensor.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct DistanceStatus {
+    /// The distance to the detected object in millimeters.
+    pub distance: u32,
+    /// The confidence in the distance measurement from 0.0 to 1.0.
+    pub confidence: f64,
+    /// The relative size of the detected object from 0 to 400.
+    pub object_size: i32,
+    /// The velocity of the detected object in m/s.
+    pub object_velocity: f64,
+}
+
 /// A physical distance sensor plugged into a port.
 /// Distance sensors can only keep track of one object at a time.
 #[derive(Debug, Eq, PartialEq)]
@@ -27,7 +40,7 @@ impl DistanceSensor {
         Ok(unsafe { bail_on!(PROS_ERR, pros_sys::distance_get(self.port.index())) as u32 })
     }
 
-    /// returns the velocity of the object the sensor detects in m/s
+    /// Returns the velocity of the object the sensor detects in m/s.
     pub fn object_velocity(&self) -> Result<f64, PortError> {
         // all VEX Distance Sensor functions return PROS_ERR on failure even though
         // some return floating point values (not PROS_ERR_F)
@@ -39,16 +52,41 @@ impl DistanceSensor {
         })
     }
 
+    /// Returns the confidence in the distance measurement from 0.0 to 1.0.
+    ///
+    /// Confidence is only available when the object is further than 200mm from the sensor,
+    /// and readings near the edge of the sensor's range will typically have a low confidence.
+    pub fn confidence(&self) -> Result<f64, PortError> {
+        // 0 -> 63
+        let confidence = bail_on!(PROS_ERR, unsafe {
+            pros_sys::distance_get_confidence(self.port.index())
+        }) as f64;
+        Ok(confidence / 63.0)
+    }
+
     /// Returns the confidence in the distance measurement from 0% to 100%.
+    #[deprecated(note = "use `DistanceSensor::confidence` instead")]
     pub fn distance_confidence(&self) -> Result<f32, PortError> {
-        // 0 -> 63
-        let confidence = unsafe {
-            bail_on!(
-                PROS_ERR,
-                pros_sys::distance_get_confidence(self.port.index())
-            )
-        } as f32;
-        Ok(confidence * 100.0 / 63.0)
+        Ok((self.confidence()? * 100.0) as f32)
+    }
+
+    /// Returns the relative size of the object the sensor detects from 0 to 400.
+    ///
+    /// A 18" x 30" grey card will return a value of approximately 75 in typical room lighting.
+    pub fn object_size(&self) -> Result<i32, PortError> {
+        Ok(bail_on!(PROS_ERR, unsafe {
+            pros_sys::distance_get_object_size(self.port.index())
+        }))
+    }
+
+    /// Returns all of the sensor's readings at once.
+    pub fn status(&self) -> Result<DistanceStatus, PortError> {
+        Ok(DistanceStatus {
+            distance: self.distance()?,
+            confidence: self.confidence()?,
+            object_size: self.object_size()?,
+            object_velocity: self.object_velocity()?,
+        })
     }
 }
 
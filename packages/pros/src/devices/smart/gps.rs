@@ -3,12 +3,20 @@
 //! A notable differenc between this API and that of PROS
 //! is that [`GpsSensor::status`] returns acceleration along with other status data.
 
+use core::time::Duration;
+
 use pros_sys::{PROS_ERR, PROS_ERR_F};
 use snafu::Snafu;
 
-use super::{SmartDevice, SmartDeviceType, SmartPort};
+use super::{
+    imu::{Acceleration, GyroRate},
+    round_data_rate, SmartDevice, SmartDeviceType, SmartPort,
+};
 use crate::error::{bail_on, map_errno, PortError};
 
+/// The minimum data rate that the GPS sensor's internal IMU can be set to.
+pub const GPS_MIN_DATA_RATE: Duration = Duration::from_millis(5);
+
 /// Represents the data output from a GPS sensor.
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
 pub struct GpsStatus {
@@ -24,6 +32,30 @@ pub struct GpsStatus {
     pub accel_z: f64,
 }
 
+impl TryFrom<pros_sys::gps_raw_s> for GyroRate {
+    type Error = GpsError;
+
+    fn try_from(value: pros_sys::gps_raw_s) -> Result<GyroRate, GpsError> {
+        Ok(Self {
+            x: bail_on!(PROS_ERR_F, value.x),
+            y: bail_on!(PROS_ERR_F, value.y),
+            z: bail_on!(PROS_ERR_F, value.z),
+        })
+    }
+}
+
+impl TryFrom<pros_sys::gps_raw_s> for Acceleration {
+    type Error = GpsError;
+
+    fn try_from(value: pros_sys::gps_raw_s) -> Result<Acceleration, GpsError> {
+        Ok(Self {
+            x: bail_on!(PROS_ERR_F, value.x),
+            y: bail_on!(PROS_ERR_F, value.y),
+            z: bail_on!(PROS_ERR_F, value.z),
+        })
+    }
+}
+
 /// A physical GPS sensor plugged into a port.
 #[derive(Debug, Eq, PartialEq)]
 pub struct GpsSensor {
@@ -32,17 +64,52 @@ pub struct GpsSensor {
 
 impl GpsSensor {
     /// Creates a new GPS sensor on the given port.
+    ///
+    /// The sensor starts with no offset at the center of the field, facing north.
     pub fn new(port: SmartPort) -> Result<Self, GpsError> {
+        Self::new_with_pose(port, (0.0, 0.0), (0.0, 0.0), 0.0)
+    }
+
+    /// Creates a new GPS sensor on the given port, starting at a known position on the field.
+    ///
+    /// - `offset` is the position of the sensor relative to the robot's center of turning, in meters.
+    /// - `position` is the robot's position in meters, with (0, 0) at the center of the field.
+    /// - `heading` is the robot's heading in degrees clockwise from north on the field.
+    pub fn new_with_pose(
+        port: SmartPort,
+        offset: (f64, f64),
+        position: (f64, f64),
+        heading: f64,
+    ) -> Result<Self, GpsError> {
         unsafe {
             bail_on!(
                 PROS_ERR,
-                pros_sys::gps_initialize_full(port.index(), 0.0, 0.0, 0.0, 0.0, 0.0)
+                pros_sys::gps_initialize_full(
+                    port.index(),
+                    position.0,
+                    position.1,
+                    heading,
+                    offset.0,
+                    offset.1
+                )
             );
         }
 
         Ok(Self { port })
     }
 
+    /// Sets the robot's position in meters, with (0, 0) at the center of the field,
+    /// and its heading in degrees clockwise from north on the field.
+    pub fn set_pose(&mut self, x: f64, y: f64, heading: f64) -> Result<(), GpsError> {
+        unsafe {
+            bail_on!(
+                PROS_ERR,
+                pros_sys::gps_set_position(self.port.index(), x, y, heading)
+            );
+        }
+        Ok(())
+    }
+
     /// Sets the offset of the GPS sensor, relative to the sensor of turning, in meters.
     pub fn set_offset(&mut self, x: f64, y: f64) -> Result<(), GpsError> {
         unsafe {
@@ -57,27 +124,46 @@ impl GpsSensor {
     }
 
     /// Gets the status of the GPS sensor.
+    ///
+    /// This reads the position, heading, and acceleration of the sensor separately,
+    /// so [`GpsSensor::heading`] or [`GpsSensor::accel`] should be used if only one of them is needed.
     pub fn status(&self) -> Result<GpsStatus, GpsError> {
-        unsafe {
-            let status = pros_sys::gps_get_status(self.port.index());
-            bail_on!(PROS_ERR_F, status.x);
-            let accel = pros_sys::gps_get_accel(self.port.index());
-            bail_on!(PROS_ERR_F, accel.x);
-            let heading = bail_on!(PROS_ERR_F, pros_sys::gps_get_heading(self.port.index()));
-
-            Ok(GpsStatus {
-                x: status.x,
-                y: status.y,
-                pitch: status.pitch,
-                roll: status.roll,
-                yaw: status.yaw,
-                heading,
-
-                accel_x: accel.x,
-                accel_y: accel.y,
-                accel_z: accel.z,
-            })
-        }
+        let status = unsafe { pros_sys::gps_get_status(self.port.index()) };
+        bail_on!(PROS_ERR_F, status.x);
+        let accel = self.accel()?;
+        let heading = self.heading()?;
+
+        Ok(GpsStatus {
+            x: status.x,
+            y: status.y,
+            pitch: status.pitch,
+            roll: status.roll,
+            yaw: status.yaw,
+            heading,
+
+            accel_x: accel.x,
+            accel_y: accel.y,
+            accel_z: accel.z,
+        })
+    }
+
+    /// Gets the heading of the GPS sensor in degrees clockwise from north on the field, from 0 to 360.
+    pub fn heading(&self) -> Result<f64, GpsError> {
+        Ok(bail_on!(PROS_ERR_F, unsafe {
+            pros_sys::gps_get_heading(self.port.index())
+        }))
+    }
+
+    /// Gets the rotation rates of the GPS sensor's internal IMU in degrees per second.
+    pub fn gyro_rate(&self) -> Result<GyroRate, GpsError> {
+        unsafe { pros_sys::gps_get_gyro_rate(self.port.index()).try_into() }
+    }
+
+    /// Gets the acceleration of the GPS sensor's internal IMU.
+    ///
+    /// Each component is the acceleration along that axis in G (9.81 m/s²).
+    pub fn accel(&self) -> Result<Acceleration, GpsError> {
+        unsafe { pros_sys::gps_get_accel(self.port.index()).try_into() }
     }
 
     /// Zeroes the rotation of the GPS sensor.
@@ -87,6 +173,20 @@ impl GpsSensor {
         }
         Ok(())
     }
+
+    /// Sets the internal data rate of the GPS sensor's IMU.
+    ///
+    /// Like the [`InertialSensor`](super::InertialSensor), the GPS sensor only supports data rates
+    /// in multiples of [`GPS_MIN_DATA_RATE`] (5 milliseconds), so the given duration is rounded
+    /// to the nearest multiple. Durations below the minimum are clamped to 5 milliseconds.
+    pub fn set_data_rate(&mut self, data_rate: Duration) -> Result<(), GpsError> {
+        let rate_ms = round_data_rate(data_rate, GPS_MIN_DATA_RATE);
+
+        bail_on!(PROS_ERR, unsafe {
+            pros_sys::gps_set_data_rate(self.port.index(), rate_ms)
+        });
+        Ok(())
+    }
 }
 
 impl SmartDevice for GpsSensor {
@@ -7,7 +7,7 @@ use core::{
 use pros_sys::{PROS_ERR, PROS_ERR_F};
 use snafu::Snafu;
 
-use super::{SmartDevice, SmartDeviceType, SmartPort};
+use super::{round_data_rate, SmartDevice, SmartDeviceType, SmartPort};
 use crate::{
     error::{bail_on, map_errno, take_errno, FromErrno, PortError},
     time::Instant,
@@ -41,9 +41,10 @@ impl InertialSensor {
 
     /// Calibrate IMU asynchronously.
     ///
-    /// Returns an [`InertialCalibrationFuture`] that is be polled until the IMU status flag reports the sensor as
+    /// Returns an [`InertialCalibrateFuture`] that is be polled until the IMU status flag reports the sensor as
     /// no longer calibrating.
     /// There a 3 second timeout that will return [`InertialError::CalibrationTimedOut`] if the timeout is exceeded.
+    /// If the sensor is disconnected during calibration, the future will resolve to [`InertialError::Port`].
     pub fn calibrate(&mut self) -> InertialCalibrateFuture {
         InertialCalibrateFuture::Calibrate(self.port.index())
     }
@@ -100,22 +101,32 @@ impl InertialSensor {
     }
 
     /// Get a quaternion representing the Inertial Sensor’s orientation.
+    ///
+    /// This provides full 3D orientation, which is useful for fusing IMU readings
+    /// with other sensors such as the [`GpsSensor`](super::gps::GpsSensor).
     pub fn quaternion(&self) -> Result<Quaternion, InertialError> {
         unsafe { pros_sys::imu_get_quaternion(self.port.index()).try_into() }
     }
 
     /// Get the Euler angles representing the Inertial Sensor’s orientation.
+    ///
+    /// All angles are measured in degrees and bounded by (-180, 180).
     pub fn euler(&self) -> Result<Euler, InertialError> {
         unsafe { pros_sys::imu_get_euler(self.port.index()).try_into() }
     }
 
     /// Get the Inertial Sensor’s raw gyroscope values.
-    pub fn gyro_rate(&self) -> Result<InertialRaw, InertialError> {
+    ///
+    /// Each component is the angular velocity about that axis in degrees per second.
+    pub fn gyro_rate(&self) -> Result<GyroRate, InertialError> {
         unsafe { pros_sys::imu_get_gyro_rate(self.port.index()).try_into() }
     }
 
     /// Get the Inertial Sensor’s raw accelerometer values.
-    pub fn accel(&self) -> Result<InertialRaw, InertialError> {
+    ///
+    /// Each component is the acceleration along that axis in G (9.81 m/s²). Sudden spikes
+    /// in these values can be used to detect collisions.
+    pub fn accel(&self) -> Result<Acceleration, InertialError> {
         unsafe { pros_sys::imu_get_accel(self.port.index()).try_into() }
     }
 
@@ -184,6 +195,8 @@ impl InertialSensor {
     }
 
     /// Sets the current reading of the Inertial Sensor’s rotation to target value.
+    ///
+    /// Unlike the heading, rotation is unbounded and is not wrapped.
     pub fn set_rotation(&mut self, rotation: f64) -> Result<(), InertialError> {
         bail_on!(PROS_ERR, unsafe {
             pros_sys::imu_set_rotation(self.port.index(), rotation)
@@ -193,8 +206,19 @@ impl InertialSensor {
 
     /// Sets the current reading of the Inertial Sensor’s heading to target value.
     ///
-    /// Target will default to 360 if above 360 and default to 0 if below 0.
+    /// Headings outside of [0, 360) degrees are wrapped into that range,
+    /// so a heading of -90 is equivalent to a heading of 270.
     pub fn set_heading(&mut self, heading: f64) -> Result<(), InertialError> {
+        // Equivalent to `heading.rem_euclid(360.0)`, which is only available with `std`.
+        let mut heading = heading % 360.0;
+        if heading < 0.0 {
+            heading += 360.0;
+        }
+        // Tiny negative headings round up to exactly 360 when wrapped.
+        if heading >= 360.0 {
+            heading = 0.0;
+        }
+
         bail_on!(PROS_ERR, unsafe {
             pros_sys::imu_set_heading(self.port.index(), heading)
         });
@@ -233,24 +257,15 @@ impl InertialSensor {
 
     /// Sets the update rate of the IMU.
     ///
-    /// This duration must be above [`IMU_MIN_DATA_RATE`] (5 milliseconds).
+    /// The IMU only supports data rates in multiples of [`IMU_MIN_DATA_RATE`] (5 milliseconds),
+    /// so the given duration is rounded to the nearest multiple. Durations below the minimum
+    /// are silently clamped to 5 milliseconds rather than returning an error, matching PROS.
     pub fn set_data_rate(&mut self, data_rate: Duration) -> Result<(), InertialError> {
-        unsafe {
-            let rate_ms = if data_rate > IMU_MIN_DATA_RATE {
-                if let Ok(rate) = u32::try_from(data_rate.as_millis()) {
-                    rate
-                } else {
-                    return Err(InertialError::InvalidDataRate);
-                }
-            } else {
-                return Err(InertialError::InvalidDataRate);
-            };
-
-            bail_on!(
-                PROS_ERR,
-                pros_sys::imu_set_data_rate(self.port.index(), rate_ms)
-            );
-        }
+        let rate_ms = round_data_rate(data_rate, IMU_MIN_DATA_RATE);
+
+        bail_on!(PROS_ERR, unsafe {
+            pros_sys::imu_set_data_rate(self.port.index(), rate_ms)
+        });
         Ok(())
     }
 }
@@ -309,13 +324,13 @@ impl From<Quaternion> for pros_sys::quaternion_s_t {
 /// A 3-axis set of euler angles.
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
 pub struct Euler {
-    /// The angle measured along the pitch axis.
+    /// The angle measured along the pitch axis in degrees.
     pub pitch: f64,
 
-    /// The angle measured along the roll axis.
+    /// The angle measured along the roll axis in degrees.
     pub roll: f64,
 
-    /// The angle measured along the yaw axis.
+    /// The angle measured along the yaw axis in degrees.
     pub yaw: f64,
 }
 
@@ -341,30 +356,56 @@ impl From<Euler> for pros_sys::euler_s_t {
     }
 }
 
-/// Represents raw data reported by the IMU.
+/// The angular velocity reported by the Inertial Sensor's gyroscope.
 ///
-/// This is effectively a 3D vector containing either angular velocity or
-/// acceleration values depending on the type of data requested..
+/// Each component is the angular velocity about that axis in degrees per second.
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
-pub struct InertialRaw {
-    /// The x component of the raw data.
+pub struct GyroRate {
+    /// The angular velocity about the x axis.
     pub x: f64,
 
-    /// The y component of the raw data.
+    /// The angular velocity about the y axis.
     pub y: f64,
 
-    /// The z component of the raw data.
+    /// The angular velocity about the z axis.
     pub z: f64,
 }
 
-impl TryFrom<pros_sys::imu_raw_s> for InertialRaw {
+impl TryFrom<pros_sys::imu_raw_s> for GyroRate {
     type Error = InertialError;
 
-    fn try_from(value: pros_sys::imu_raw_s) -> Result<InertialRaw, InertialError> {
+    fn try_from(value: pros_sys::imu_raw_s) -> Result<GyroRate, InertialError> {
         Ok(Self {
             x: bail_on!(PROS_ERR_F, value.x),
-            y: value.y,
-            z: value.z,
+            y: bail_on!(PROS_ERR_F, value.y),
+            z: bail_on!(PROS_ERR_F, value.z),
+        })
+    }
+}
+
+/// The acceleration reported by the Inertial Sensor's accelerometer.
+///
+/// Each component is the acceleration along that axis in G (9.81 m/s²).
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Acceleration {
+    /// The acceleration along the x axis.
+    pub x: f64,
+
+    /// The acceleration along the y axis.
+    pub y: f64,
+
+    /// The acceleration along the z axis.
+    pub z: f64,
+}
+
+impl TryFrom<pros_sys::imu_raw_s> for Acceleration {
+    type Error = InertialError;
+
+    fn try_from(value: pros_sys::imu_raw_s) -> Result<Acceleration, InertialError> {
+        Ok(Self {
+            x: bail_on!(PROS_ERR_F, value.x),
+            y: bail_on!(PROS_ERR_F, value.y),
+            z: bail_on!(PROS_ERR_F, value.z),
         })
     }
 }
@@ -388,6 +429,9 @@ impl TryFrom<pros_sys::imu_status_e_t> for InertialStatus {
     }
 }
 
+/// A future that calibrates an [`InertialSensor`] and completes once calibration has finished.
+///
+/// Created by [`InertialSensor::calibrate`].
 #[derive(Debug, Clone, Copy)]
 pub enum InertialCalibrateFuture {
     Calibrate(u8),
@@ -415,7 +459,7 @@ impl core::future::Future for InertialCalibrateFuture {
                 let is_calibrating = match unsafe { pros_sys::imu_get_status(port) } {
                     pros_sys::E_IMU_STATUS_ERROR => {
                         let errno = take_errno();
-                        return Poll::Ready(Err(InertialError::from_errno(take_errno())
+                        return Poll::Ready(Err(InertialError::from_errno(errno)
                             .unwrap_or_else(|| panic!("Unknown errno code {errno}"))));
                     }
                     value => (value & pros_sys::E_IMU_STATUS_CALIBRATING) != 0,
@@ -438,8 +482,6 @@ impl core::future::Future for InertialCalibrateFuture {
 pub enum InertialError {
     #[snafu(display("Inertial sensor is still calibrating, but exceeded calibration timeout."))]
     CalibrationTimedOut,
-    #[snafu(display("Sensor data rate has a minimum duration of 5 milliseconds."))]
-    InvalidDataRate,
     #[snafu(display("{source}"), context(false))]
     Port { source: PortError },
 }
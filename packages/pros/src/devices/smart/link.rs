@@ -2,12 +2,17 @@
 //!
 //! There are two types of links: [`TxLink`] (transmitter radio module) and [`RxLink`] (receiver radio module).
 //! both implement a shared trait [`Link`] as well as a no_std version of `Write` and `Read` from [`no_std_io`] respectively.
+//!
+//! Structured messages can be sent with [`TxLink::send_message`] and received with [`RxLink::receive_message`].
+//! Messages are serialized using [`postcard`] and prefixed with their length so that they can be
+//! reassembled from partial reads.
 
-use alloc::{ffi::CString, string::String};
+use alloc::{ffi::CString, string::String, vec::Vec};
 use core::ffi::CStr;
 
 use no_std_io::io;
 use pros_sys::{link_receive, link_transmit, E_LINK_RECEIVER, E_LINK_TRANSMITTER};
+use serde::{de::DeserializeOwned, Serialize};
 use snafu::Snafu;
 
 use super::{SmartDevice, SmartDeviceType, SmartPort};
@@ -19,34 +24,61 @@ pub trait Link: SmartDevice {
     fn id(&self) -> &CStr;
 
     /// Check whether this link is connected to another robot.
-    fn connected(&self) -> bool {
+    ///
+    /// Data can only be sent or received while the link is connected.
+    fn is_connected(&self) -> bool {
         unsafe { pros_sys::link_connected(self.port_index()) }
     }
 
+    /// Check whether this link is connected to another robot.
+    #[deprecated(note = "use `Link::is_connected` instead")]
+    fn connected(&self) -> bool {
+        self.is_connected()
+    }
+
+    /// Returns the number of bytes waiting to be read from the receive buffer.
+    fn bytes_available(&self) -> Result<usize, LinkError> {
+        let num = unsafe {
+            bail_on!(
+                pros_sys::PROS_ERR as _,
+                pros_sys::link_raw_receivable_size(self.port_index())
+            )
+        };
+
+        Ok(num as usize)
+    }
+
     /// Create a new link ready to send or recieve data.
     fn new(port: SmartPort, id: String, vexlink_override: bool) -> Result<Self, LinkError>
     where
         Self: Sized;
 }
 
+/// The size of the length prefix sent before every message.
+const MESSAGE_HEADER_SIZE: usize = core::mem::size_of::<u16>();
+
+/// The size in bytes of the radio's transmit and receive buffers.
+pub const LINK_BUFFER_SIZE: usize = 512;
+
+/// The largest serialized message that can be sent with [`TxLink::send_message`].
+///
+/// A whole message, including its length prefix, must fit in the transmit buffer at once.
+pub const MAX_MESSAGE_SIZE: usize = LINK_BUFFER_SIZE - MESSAGE_HEADER_SIZE;
+
 /// A recieving end of a VEXLink connection.
 pub struct RxLink {
     port: SmartPort,
     id: CString,
+    message_buf: Vec<u8>,
 }
 
 impl RxLink {
+    /// Returns the number of bytes available to be read from the receive buffer.
     pub fn num_incoming_bytes(&self) -> Result<u32, LinkError> {
-        let num = unsafe {
-            bail_on!(
-                pros_sys::PROS_ERR as _,
-                pros_sys::link_raw_receivable_size(self.port.index())
-            )
-        };
-
-        Ok(num)
+        Ok(self.bytes_available()? as u32)
     }
 
+    /// Clears all data in the receive buffer.
     pub fn clear_incoming_buf(&self) -> Result<(), LinkError> {
         unsafe {
             bail_on!(
@@ -58,6 +90,8 @@ impl RxLink {
         Ok(())
     }
 
+    /// Receives a packet sent with [`TxLink::transmit`] into the given buffer,
+    /// returning the number of bytes read.
     pub fn receive(&self, buf: &mut [u8]) -> Result<u32, LinkError> {
         const PROS_ERR_U32: u32 = pros_sys::PROS_ERR as _;
 
@@ -70,6 +104,51 @@ impl RxLink {
             n => Ok(n),
         }
     }
+
+    /// Receives a message sent with [`TxLink::send_message`].
+    ///
+    /// Incoming data is buffered until a complete message has been received,
+    /// so this returns `None` if no complete message is available yet.
+    ///
+    /// If a length prefix is larger than [`MAX_MESSAGE_SIZE`], the incoming data is corrupted
+    /// or out of sync. The buffered data is then discarded and [`LinkError::CorruptMessage`] is returned.
+    pub fn receive_message<T: DeserializeOwned>(&mut self) -> Result<Option<T>, LinkError> {
+        let available = self.bytes_available()?.min(u16::MAX as usize);
+        if available > 0 {
+            let start = self.message_buf.len();
+            self.message_buf.resize(start + available, 0);
+            let read = unsafe {
+                bail_on!(
+                    pros_sys::PROS_ERR as _,
+                    pros_sys::link_receive_raw(
+                        self.port.index(),
+                        self.message_buf[start..].as_mut_ptr().cast(),
+                        available as _,
+                    )
+                )
+            };
+            self.message_buf.truncate(start + read as usize);
+        }
+
+        if self.message_buf.len() < MESSAGE_HEADER_SIZE {
+            return Ok(None);
+        }
+        let len = u16::from_le_bytes([self.message_buf[0], self.message_buf[1]]) as usize;
+        if len > MAX_MESSAGE_SIZE {
+            self.message_buf.clear();
+            return Err(LinkError::CorruptMessage);
+        }
+        let frame_len = MESSAGE_HEADER_SIZE + len;
+        if self.message_buf.len() < frame_len {
+            return Ok(None);
+        }
+
+        let message = postcard::from_bytes(&self.message_buf[MESSAGE_HEADER_SIZE..frame_len])
+            .map_err(|error| LinkError::Serialization { error });
+        self.message_buf.drain(..frame_len);
+
+        Ok(Some(message?))
+    }
 }
 
 impl Link for RxLink {
@@ -88,7 +167,11 @@ impl Link for RxLink {
                 }
             )
         };
-        Ok(Self { port, id })
+        Ok(Self {
+            port,
+            id,
+            message_buf: Vec::new(),
+        })
     }
 }
 
@@ -118,9 +201,11 @@ pub struct TxLink {
 }
 
 impl TxLink {
-    // I have literally no idea what the purpose of this is,
-    // there is no way to push to the transmission buffer without transmitting it.
-    pub fn num_outgoing_bytes(&self) -> Result<u32, LinkError> {
+    /// Returns the number of bytes of free space in the transmission buffer.
+    ///
+    /// This can be used to back off from sending data when the buffer is full
+    /// rather than having transmissions fail with [`LinkError::BufferBusyFull`].
+    pub fn transmit_space(&self) -> Result<u32, LinkError> {
         let num = unsafe {
             bail_on!(
                 pros_sys::PROS_ERR as _,
@@ -131,6 +216,14 @@ impl TxLink {
         Ok(num)
     }
 
+    /// Returns the number of bytes of free space in the transmission buffer.
+    #[deprecated(note = "use `TxLink::transmit_space` instead")]
+    pub fn num_outgoing_bytes(&self) -> Result<u32, LinkError> {
+        self.transmit_space()
+    }
+
+    /// Transmits the given data as a packet with a start byte and checksum,
+    /// returning the number of bytes transmitted.
     pub fn transmit(&self, buf: &[u8]) -> Result<u32, LinkError> {
         const PROS_ERR_U32: u32 = pros_sys::PROS_ERR as _;
 
@@ -144,6 +237,45 @@ impl TxLink {
             n => Ok(n),
         }
     }
+
+    /// Sends a message that can be received with [`RxLink::receive_message`].
+    ///
+    /// Returns [`LinkError::MessageTooLarge`] rather than truncating the message if it is larger
+    /// than [`MAX_MESSAGE_SIZE`] once serialized. If the message fits but the transmission buffer
+    /// does not currently have room for it, [`LinkError::BufferBusyFull`] is returned and the
+    /// message can be sent again later.
+    pub fn send_message<T: Serialize>(&mut self, message: &T) -> Result<(), LinkError> {
+        let payload =
+            postcard::to_allocvec(message).map_err(|error| LinkError::Serialization { error })?;
+        if payload.len() > MAX_MESSAGE_SIZE {
+            return Err(LinkError::MessageTooLarge);
+        }
+
+        let mut frame = Vec::with_capacity(MESSAGE_HEADER_SIZE + payload.len());
+        frame.extend_from_slice(&(payload.len() as u16).to_le_bytes());
+        frame.extend_from_slice(&payload);
+
+        if frame.len() > self.transmit_space()? as usize {
+            return Err(LinkError::BufferBusyFull);
+        }
+
+        let sent = unsafe {
+            bail_on!(
+                pros_sys::PROS_ERR as _,
+                pros_sys::link_transmit_raw(
+                    self.port.index(),
+                    frame.as_ptr().cast(),
+                    frame.len() as _,
+                )
+            )
+        };
+        // A partial frame breaks the length prefixes of every message after it.
+        if sent as usize != frame.len() {
+            return Err(LinkError::IncompleteTransmission);
+        }
+
+        Ok(())
+    }
 }
 
 impl io::Write for TxLink {
@@ -204,6 +336,16 @@ pub enum LinkError {
     Protocol,
     #[snafu(display("The link is busy."))]
     Busy,
+    #[snafu(display("The message is too large to fit in the link's transmission buffer."))]
+    MessageTooLarge,
+    #[snafu(display("Only part of the message was transmitted, so the receiver may have lost track of message boundaries."))]
+    IncompleteTransmission,
+    #[snafu(display(
+        "A received message had an invalid length, so the buffered data was discarded."
+    ))]
+    CorruptMessage,
+    #[snafu(display("Failed to serialize or deserialize a message: {error}"))]
+    Serialization { error: postcard::Error },
     #[snafu(display("{source}"), context(false))]
     Port { source: PortError },
 }
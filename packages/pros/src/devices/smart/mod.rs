@@ -21,15 +21,19 @@ pub mod link;
 pub mod motor;
 pub mod optical;
 pub mod rotation;
+pub mod serial;
 pub mod vision;
 
+use core::time::Duration;
+
 pub use distance::DistanceSensor;
 pub use gps::GpsSensor;
 pub use imu::InertialSensor;
 pub use link::{Link, RxLink, TxLink};
-pub use motor::Motor;
+pub use motor::{Motor, MotorGroup};
 pub use optical::OpticalSensor;
 pub use rotation::RotationSensor;
+pub use serial::SerialPort;
 pub use vision::VisionSensor;
 
 use crate::{error::bail_on, prelude::PortError};
@@ -87,8 +91,47 @@ impl SmartPort {
     }
 
     /// Get the type of device currently connected to this port.
-    pub fn connected_type(&self) -> Result<SmartDeviceType, PortError> {
-        unsafe { pros_sys::apix::registry_get_plugged_type(self.index() - 1).try_into() }
+    ///
+    /// Returns `None` if nothing is plugged into the port.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// for index in 1..=21 {
+    ///     let port = unsafe { SmartPort::new(index) };
+    ///     println!("Port {index}: {:?}", port.connected_type()?);
+    /// }
+    /// ```
+    pub fn connected_type(&self) -> Result<Option<SmartDeviceType>, PortError> {
+        let device_type: SmartDeviceType =
+            unsafe { pros_sys::apix::registry_get_plugged_type(self.index() - 1).try_into()? };
+
+        Ok(match device_type {
+            SmartDeviceType::None => None,
+            device_type => Some(device_type),
+        })
+    }
+
+    /// Checks that the device connected to this port is of the given type.
+    ///
+    /// This is useful for catching wiring mistakes when creating a device:
+    ///
+    /// ```ignore
+    /// let port = peripherals.port_1;
+    /// port.validate_type(SmartDeviceType::Motor)?;
+    /// let motor = Motor::new(port, BrakeMode::Brake)?;
+    /// ```
+    ///
+    /// Returns [`PortError::DeviceTypeMismatch`] if a different device, or no device, is connected.
+    pub fn validate_type(&self, device_type: SmartDeviceType) -> Result<(), PortError> {
+        let found = self.connected_type()?.unwrap_or(SmartDeviceType::None);
+        if found != device_type {
+            return Err(PortError::DeviceTypeMismatch {
+                expected: device_type,
+                found,
+            });
+        }
+        Ok(())
     }
 
     /// Get the type of device this port is configured as.
@@ -131,6 +174,7 @@ impl TryFrom<pros_sys::apix::v5_device_e_t> for SmartDeviceType {
             pros_sys::apix::E_DEVICE_DISTANCE => Self::Distance,
             pros_sys::apix::E_DEVICE_VISION => Self::Vision,
             pros_sys::apix::E_DEVICE_OPTICAL => Self::Optical,
+            pros_sys::apix::E_DEVICE_GPS => Self::Gps,
             pros_sys::apix::E_DEVICE_RADIO => Self::Radio,
             pros_sys::apix::E_DEVICE_ADI => Self::Adi,
             pros_sys::apix::E_DEVICE_SERIAL => Self::Serial,
@@ -144,3 +188,11 @@ impl From<SmartDeviceType> for pros_sys::apix::v5_device_e_t {
         value as _
     }
 }
+
+/// Rounds a sensor data rate to the nearest multiple of `min` in milliseconds,
+/// clamping it so that it is never below `min`.
+pub(crate) fn round_data_rate(rate: Duration, min: Duration) -> u32 {
+    let min_ms = min.as_millis() as u32;
+    let rate_ms = u32::try_from(rate.as_millis()).unwrap_or(u32::MAX);
+    (rate_ms.saturating_add(min_ms / 2) / min_ms * min_ms).max(min_ms)
+}
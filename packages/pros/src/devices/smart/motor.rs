@@ -13,30 +13,42 @@
 //! Example of driving a single motor with a controller:
 //! ```rust
 //! # use pros::prelude::*;
+//! # use pros::devices::controller::{Controller, ControllerId};
 //! let motor = Motor::new(1, BrakeMode::Brake).unwrap();
-//! let controller = Controller::Master;
+//! let controller = Controller::new(ControllerId::Master);
 //! loop {
 //!     let output = controller.state().joysticks.left.y;
 //!     motor.set_output(output).ok();
 //! }
 //! ```
 
+use alloc::vec::Vec;
+use core::time::Duration;
+
 use pros_sys::{PROS_ERR, PROS_ERR_F};
 use snafu::Snafu;
 
 use super::{SmartDevice, SmartDeviceType, SmartPort};
 use crate::{
-    devices::Position,
+    devices::{Position, Velocity},
     error::{bail_on, map_errno, PortError},
+    time::Instant,
 };
 
+/// The maximum distance a motor can be from its target position
+/// while still being considered at its target.
+pub const MOTOR_TARGET_TOLERANCE: Position = Position::Degrees(2.0);
+
+/// The maximum velocity in RPM a motor can be moving at
+/// while still being considered settled.
+pub const MOTOR_SETTLED_VELOCITY: f64 = 1.0;
+
 /// The basic motor struct.
 #[derive(Debug, Eq, PartialEq)]
 pub struct Motor {
     port: SmartPort,
 }
 
-//TODO: Implement good set_velocity and get_velocity functions.
 //TODO: Measure the number of counts per rotation. Fow now we assume it is 4096
 impl Motor {
     pub fn new(port: SmartPort, brake_mode: BrakeMode) -> Result<Self, MotorError> {
@@ -54,6 +66,9 @@ impl Motor {
         Ok(Self { port })
     }
 
+    /// Sets the gearset of the motor.
+    ///
+    /// This should match the physical gear cartridge installed in the motor.
     pub fn set_gearset(&mut self, gearset: Gearset) -> Result<(), MotorError> {
         unsafe {
             bail_on!(
@@ -64,10 +79,31 @@ impl Motor {
         Ok(())
     }
 
+    /// Returns the gearset that was set for the motor.
     pub fn gearset(&self) -> Result<Gearset, MotorError> {
         Ok(unsafe { bail_on!(PROS_ERR, pros_sys::motor_get_gearing(self.port.index())) }.into())
     }
 
+    /// Sets the units the motor's encoder reports its position in.
+    ///
+    /// Motors are configured to use [`MotorUnits::Degrees`] when created with [`Motor::new`].
+    /// Positions are converted from these units automatically, so this does not change the
+    /// [`Position`]s returned by [`Motor::position`].
+    pub fn set_encoder_units(&mut self, units: MotorUnits) -> Result<(), MotorError> {
+        bail_on!(PROS_ERR, unsafe {
+            pros_sys::motor_set_encoder_units(self.port.index(), units.into())
+        });
+        Ok(())
+    }
+
+    /// Returns the units the motor's encoder reports its position in.
+    pub fn encoder_units(&self) -> Result<MotorUnits, MotorError> {
+        Ok(bail_on!(pros_sys::E_MOTOR_ENCODER_INVALID, unsafe {
+            pros_sys::motor_get_encoder_units(self.port.index())
+        })
+        .into())
+    }
+
     /// Takes in a f32 from -1 to 1 that is scaled to -12 to 12 volts.
     /// Useful for driving motors with controllers.
     pub fn set_output(&mut self, output: f32) -> Result<(), MotorError> {
@@ -106,38 +142,95 @@ impl Motor {
         Ok(())
     }
 
-    /// Moves the motor to an absolute position, based off of when the motor was zeroed
-    /// units for the velocity is RPM.
-    pub fn set_position_absolute(
-        &mut self,
-        position: Position,
-        velocity: i32,
-    ) -> Result<(), MotorError> {
+    /// Spins the motor at a target velocity using the motor's internal velocity controller.
+    ///
+    /// Velocity is measured in RPM and ranges from +-100, +-200, or +-600 depending on the
+    /// motor's [`Gearset`].
+    pub fn set_velocity(&mut self, rpm: i32) -> Result<(), MotorError> {
+        bail_on!(PROS_ERR, unsafe {
+            pros_sys::motor_move_velocity(self.port.index(), rpm)
+        });
+        Ok(())
+    }
+
+    /// Spins the motor at a target [`Velocity`] using the motor's internal velocity controller.
+    ///
+    /// The velocity is rounded to the nearest RPM. Returns [`MotorError::VelocityOutOfRange`]
+    /// if it is faster than the [`Gearset::max_rpm`] of the motor's gearset.
+    pub fn set_velocity_typed(&mut self, velocity: Velocity) -> Result<(), MotorError> {
+        let rpm = velocity.into_rpm();
+        if rpm.is_nan() || rpm.abs() > self.gearset()?.max_rpm() {
+            return Err(MotorError::VelocityOutOfRange);
+        }
+        self.set_velocity(libm::round(rpm) as i32)
+    }
+
+    /// Moves the motor to an absolute position using the motor's internal position controller,
+    /// based off of when the motor was zeroed.
+    ///
+    /// Units for the velocity are RPM.
+    pub fn move_absolute(&mut self, position: Position, velocity: i32) -> Result<(), MotorError> {
+        let raw = self.position_to_raw(position)?;
         unsafe {
             bail_on!(
                 PROS_ERR,
-                pros_sys::motor_move_absolute(self.port.index(), position.into_degrees(), velocity)
+                pros_sys::motor_move_absolute(self.port.index(), raw, velocity)
             );
         };
         Ok(())
     }
 
-    /// Moves the motor to a position relative to the current position.
-    /// units for velocity is RPM.
-    pub fn set_position_relative(
-        &mut self,
-        position: Position,
-        velocity: i32,
-    ) -> Result<(), MotorError> {
+    /// Moves the motor by a position relative to its current target using the motor's
+    /// internal position controller.
+    ///
+    /// Units for the velocity are RPM.
+    pub fn move_relative(&mut self, delta: Position, velocity: i32) -> Result<(), MotorError> {
+        let raw = self.position_to_raw(delta)?;
         unsafe {
             bail_on!(
                 PROS_ERR,
-                pros_sys::motor_move_relative(self.port.index(), position.into_degrees(), velocity)
+                pros_sys::motor_move_relative(self.port.index(), raw, velocity)
             );
         }
         Ok(())
     }
 
+    /// Moves the motor to an absolute position, based off of when the motor was zeroed.
+    /// Units for the velocity are RPM.
+    #[deprecated(note = "use `Motor::move_absolute` instead")]
+    pub fn set_position_absolute(
+        &mut self,
+        position: Position,
+        velocity: i32,
+    ) -> Result<(), MotorError> {
+        self.move_absolute(position, velocity)
+    }
+
+    /// Moves the motor to a position relative to its current target.
+    /// Units for the velocity are RPM.
+    #[deprecated(note = "use `Motor::move_relative` instead")]
+    pub fn set_position_relative(
+        &mut self,
+        position: Position,
+        velocity: i32,
+    ) -> Result<(), MotorError> {
+        self.move_relative(position, velocity)
+    }
+
+    /// Returns the target position set by [`Motor::move_absolute`] or [`Motor::move_relative`].
+    pub fn target_position(&self) -> Result<Position, MotorError> {
+        let raw = bail_on!(PROS_ERR_F, unsafe {
+            pros_sys::motor_get_target_position(self.port.index())
+        });
+        self.position_from_raw(raw)
+    }
+
+    /// Checks if the motor is within [`MOTOR_TARGET_TOLERANCE`] of its target position.
+    pub fn is_at_target(&self) -> Result<bool, MotorError> {
+        let error = self.target_position()? - self.position()?;
+        Ok(error.into_degrees().abs() <= MOTOR_TARGET_TOLERANCE.into_degrees())
+    }
+
     /// Returns the power drawn by the motor in Watts.
     pub fn power(&self) -> Result<f64, MotorError> {
         unsafe {
@@ -158,6 +251,16 @@ impl Motor {
         }
     }
 
+    /// Returns the internal temperature of the motor in degrees Celsius.
+    pub fn temperature(&self) -> Result<f64, MotorError> {
+        unsafe {
+            Ok(bail_on!(
+                PROS_ERR_F,
+                pros_sys::motor_get_temperature(self.port.index())
+            ))
+        }
+    }
+
     /// Returns the voltage the motor is drawing in volts.
     pub fn voltage(&self) -> Result<f64, MotorError> {
         // docs say this function returns PROS_ERR_F but it actually returns PROS_ERR
@@ -167,22 +270,81 @@ impl Motor {
     }
 
     /// Returns the current position of the motor.
+    ///
+    /// This accounts for the motor's configured [`MotorUnits`] and [`Gearset`].
     pub fn position(&self) -> Result<Position, MotorError> {
-        unsafe {
-            Ok(Position::from_degrees(bail_on!(
-                PROS_ERR_F,
-                pros_sys::motor_get_position(self.port.index())
-            )))
-        }
+        let raw = bail_on!(PROS_ERR_F, unsafe {
+            pros_sys::motor_get_position(self.port.index())
+        });
+        self.position_from_raw(raw)
+    }
+
+    /// Converts a position reported by PROS in the motor's encoder units into a [`Position`].
+    fn position_from_raw(&self, raw: f64) -> Result<Position, MotorError> {
+        Ok(match self.encoder_units()? {
+            MotorUnits::Degrees => Position::from_degrees(raw),
+            MotorUnits::Rotations => Position::from_rotations(raw),
+            MotorUnits::Counts => {
+                Position::from_rotations(raw / self.gearset()?.ticks_per_revolution())
+            }
+        })
     }
 
-    /// Returns the current draw of the motor.
+    /// Converts a [`Position`] into the motor's encoder units for passing to PROS.
+    fn position_to_raw(&self, position: Position) -> Result<f64, MotorError> {
+        Ok(match self.encoder_units()? {
+            MotorUnits::Degrees => position.into_degrees(),
+            MotorUnits::Rotations => position.into_rotations(),
+            MotorUnits::Counts => {
+                position.into_rotations() * self.gearset()?.ticks_per_revolution()
+            }
+        })
+    }
+
+    /// Returns the actual velocity of the motor.
+    pub fn velocity(&self) -> Result<Velocity, MotorError> {
+        Ok(Velocity::from_rpm(bail_on!(PROS_ERR_F, unsafe {
+            pros_sys::motor_get_actual_velocity(self.port.index())
+        })))
+    }
+
+    /// Returns the current draw of the motor in milliamps.
     pub fn current_draw(&self) -> Result<i32, MotorError> {
         Ok(bail_on!(PROS_ERR, unsafe {
             pros_sys::motor_get_current_draw(self.port.index())
         }))
     }
 
+    /// Sets the maximum current the motor can draw in milliamps.
+    pub fn set_current_limit(&mut self, milliamps: i32) -> Result<(), MotorError> {
+        bail_on!(PROS_ERR, unsafe {
+            pros_sys::motor_set_current_limit(self.port.index(), milliamps)
+        });
+        Ok(())
+    }
+
+    /// Returns the maximum current the motor can draw in milliamps.
+    pub fn current_limit(&self) -> Result<i32, MotorError> {
+        Ok(bail_on!(PROS_ERR, unsafe {
+            pros_sys::motor_get_current_limit(self.port.index())
+        }))
+    }
+
+    /// Sets the maximum voltage the motor can be driven with in millivolts.
+    pub fn set_voltage_limit(&mut self, millivolts: i32) -> Result<(), MotorError> {
+        bail_on!(PROS_ERR, unsafe {
+            pros_sys::motor_set_voltage_limit(self.port.index(), millivolts)
+        });
+        Ok(())
+    }
+
+    /// Returns the maximum voltage the motor can be driven with in millivolts.
+    pub fn voltage_limit(&self) -> Result<i32, MotorError> {
+        Ok(bail_on!(PROS_ERR, unsafe {
+            pros_sys::motor_get_voltage_limit(self.port.index())
+        }))
+    }
+
     /// Sets the current encoder position to zero without moving the motor.
     /// Analogous to taring or resetting the encoder to the current position.
     pub fn zero(&mut self) -> Result<(), MotorError> {
@@ -192,7 +354,10 @@ impl Motor {
         Ok(())
     }
 
-    /// Stops the motor based on the current [`BrakeMode`]
+    /// Stops the motor based on the current [`BrakeMode`].
+    ///
+    /// Unlike setting the voltage to zero, which always lets the motor coast,
+    /// this actively slows the motor with [`BrakeMode::Brake`] and holds its position with [`BrakeMode::Hold`].
     pub fn brake(&mut self) -> Result<(), MotorError> {
         bail_on!(PROS_ERR, unsafe {
             pros_sys::motor_brake(self.port.index())
@@ -203,8 +368,9 @@ impl Motor {
     /// Sets the current encoder position to the given position without moving the motor.
     /// Analogous to taring or resetting the encoder so that the new position is equal to the given position.
     pub fn set_zero_position(&mut self, position: Position) -> Result<(), MotorError> {
+        let raw = self.position_to_raw(position)?;
         bail_on!(PROS_ERR, unsafe {
-            pros_sys::motor_set_zero_position(self.port.index(), position.into_degrees())
+            pros_sys::motor_set_zero_position(self.port.index(), raw)
         });
         Ok(())
     }
@@ -217,6 +383,14 @@ impl Motor {
         Ok(())
     }
 
+    /// Returns how the motor acts when stopping.
+    pub fn brake_mode(&self) -> Result<BrakeMode, MotorError> {
+        Ok(bail_on!(pros_sys::E_MOTOR_BRAKE_INVALID, unsafe {
+            pros_sys::motor_get_brake_mode(self.port.index())
+        })
+        .into())
+    }
+
     //TODO: Test this, as im not entirely sure of the actual implementation
     /// Get the current state of the motor.
     pub fn get_state(&self) -> Result<MotorState, MotorError> {
@@ -226,6 +400,33 @@ impl Motor {
         Ok(bit_flags.into())
     }
 
+    /// Get the faults currently experienced by the motor.
+    pub fn faults(&self) -> Result<MotorFaults, MotorError> {
+        let bits = bail_on!(PROS_ERR as _, unsafe {
+            pros_sys::motor_get_faults(self.port.index())
+        });
+        Ok(MotorFaults::from_bits_retain(bits))
+    }
+
+    /// Returns `true` if the motor's temperature is above its limit.
+    ///
+    /// The motor reduces its power when it is too hot.
+    pub fn is_over_temp(&self) -> Result<bool, MotorError> {
+        Ok(self.faults()?.contains(MotorFaults::OVER_TEMP))
+    }
+
+    /// Returns `true` if the motor is drawing more current than its limit.
+    pub fn is_over_current(&self) -> Result<bool, MotorError> {
+        Ok(self.faults()?.contains(MotorFaults::OVER_CURRENT))
+    }
+
+    /// Returns `true` if the motor's velocity is below [`MOTOR_SETTLED_VELOCITY`].
+    ///
+    /// The measured velocity is rarely exactly zero, so a small threshold is used instead.
+    pub fn is_stopped(&self) -> Result<bool, MotorError> {
+        Ok(self.velocity()?.into_rpm().abs() < MOTOR_SETTLED_VELOCITY)
+    }
+
     /// Reverse this motor by multiplying all input by -1.
     pub fn set_reversed(&mut self, reversed: bool) -> Result<(), MotorError> {
         bail_on!(PROS_ERR, unsafe {
@@ -243,6 +444,32 @@ impl Motor {
     pub fn wait_until_stopped(&self) -> MotorStoppedFuture {
         MotorStoppedFuture { motor: self }
     }
+
+    /// Returns a future that completes when the motor has settled at its target position.
+    ///
+    /// The motor is considered settled once it is within [`MOTOR_TARGET_TOLERANCE`]
+    /// of its target and its velocity has dropped below [`MOTOR_SETTLED_VELOCITY`].
+    pub fn wait_until_settled(&self) -> MotorSettledFuture<'_> {
+        MotorSettledFuture {
+            motor: self,
+            deadline: None,
+        }
+    }
+
+    /// Returns a future that completes when the motor has settled at its target position,
+    /// or errors with [`MotorError::SettleTimedOut`] if it has not settled within `timeout`.
+    ///
+    /// See [`Motor::wait_until_settled`] for what is considered settled.
+    pub fn wait_until_settled_timeout(&self, timeout: Duration) -> MotorSettledFuture<'_> {
+        MotorSettledFuture {
+            motor: self,
+            deadline: Some(Instant::now() + timeout),
+        }
+    }
+
+    fn is_settled(&self) -> Result<bool, MotorError> {
+        Ok(self.is_stopped()? && self.is_at_target()?)
+    }
 }
 
 impl SmartDevice for Motor {
@@ -255,8 +482,105 @@ impl SmartDevice for Motor {
     }
 }
 
+/// A group of motors that are controlled together as a single unit.
+///
+/// This is useful for mechanisms such as drivetrain sides, where multiple motors
+/// must always receive the same commands. Setters are forwarded to every motor in the group,
+/// while getters return the average reading of all motors.
+#[derive(Debug, Eq, PartialEq)]
+pub struct MotorGroup {
+    motors: Vec<Motor>,
+}
+
+impl MotorGroup {
+    /// Creates a new motor group from a collection of motors.
+    ///
+    /// All motors in the group must share the same [`Gearset`], otherwise
+    /// [`MotorGroupError::GearsetMismatch`] is returned.
+    pub fn new(motors: impl IntoIterator<Item = Motor>) -> Result<Self, MotorGroupError> {
+        let motors: Vec<Motor> = motors.into_iter().collect();
+
+        let Some(first) = motors.first() else {
+            return Err(MotorGroupError::Empty);
+        };
+        let gearset = first.gearset()?;
+        for motor in &motors[1..] {
+            if motor.gearset()? != gearset {
+                return Err(MotorGroupError::GearsetMismatch);
+            }
+        }
+
+        Ok(Self { motors })
+    }
+
+    /// Returns the motors in this group.
+    pub fn motors(&self) -> &[Motor] {
+        &self.motors
+    }
+
+    /// Returns the motors in this group mutably.
+    pub fn motors_mut(&mut self) -> &mut [Motor] {
+        &mut self.motors
+    }
+
+    /// Consumes the group, returning the motors it contained.
+    pub fn into_inner(self) -> Vec<Motor> {
+        self.motors
+    }
+
+    /// Sets the voltage of every motor in the group. Must be between -12 and 12 Volts.
+    pub fn set_voltage(&mut self, voltage: f32) -> Result<(), MotorGroupError> {
+        for motor in &mut self.motors {
+            motor.set_voltage(voltage)?;
+        }
+        Ok(())
+    }
+
+    /// Spins every motor in the group at a target velocity in RPM.
+    pub fn set_velocity(&mut self, rpm: i32) -> Result<(), MotorGroupError> {
+        for motor in &mut self.motors {
+            motor.set_velocity(rpm)?;
+        }
+        Ok(())
+    }
+
+    /// Stops every motor in the group based on its current [`BrakeMode`].
+    pub fn brake(&mut self) -> Result<(), MotorGroupError> {
+        for motor in &mut self.motors {
+            motor.brake()?;
+        }
+        Ok(())
+    }
+
+    /// Sets how every motor in the group should act when stopping.
+    pub fn set_brake_mode(&mut self, brake_mode: BrakeMode) -> Result<(), MotorGroupError> {
+        for motor in &mut self.motors {
+            motor.set_brake_mode(brake_mode)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the average position of all motors in the group.
+    pub fn position(&self) -> Result<Position, MotorGroupError> {
+        let mut total = 0.0;
+        for motor in &self.motors {
+            total += motor.position()?.into_degrees();
+        }
+        Ok(Position::from_degrees(total / self.motors.len() as f64))
+    }
+
+    /// Returns the average velocity of all motors in the group.
+    pub fn velocity(&self) -> Result<Velocity, MotorGroupError> {
+        let mut total = Velocity::default();
+        for motor in &self.motors {
+            total += motor.velocity()?;
+        }
+        Ok(total / self.motors.len() as f64)
+    }
+}
+
 /// Determines how a motor should act when braking.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BrakeMode {
     /// Motor never brakes.
     None,
@@ -276,6 +600,49 @@ impl From<BrakeMode> for pros_sys::motor_brake_mode_e_t {
     }
 }
 
+impl From<pros_sys::motor_brake_mode_e_t> for BrakeMode {
+    fn from(value: pros_sys::motor_brake_mode_e_t) -> Self {
+        match value {
+            pros_sys::E_MOTOR_BRAKE_BRAKE => BrakeMode::Brake,
+            pros_sys::E_MOTOR_BRAKE_HOLD => BrakeMode::Hold,
+            pros_sys::E_MOTOR_BRAKE_COAST => BrakeMode::None,
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Units that a motor's encoder can report its position in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MotorUnits {
+    /// Position is measured in degrees.
+    Degrees,
+    /// Position is measured in full rotations.
+    Rotations,
+    /// Position is measured in raw encoder counts.
+    Counts,
+}
+
+impl From<MotorUnits> for pros_sys::motor_encoder_units_e_t {
+    fn from(other: MotorUnits) -> pros_sys::motor_encoder_units_e_t {
+        match other {
+            MotorUnits::Degrees => pros_sys::E_MOTOR_ENCODER_DEGREES,
+            MotorUnits::Rotations => pros_sys::E_MOTOR_ENCODER_ROTATIONS,
+            MotorUnits::Counts => pros_sys::E_MOTOR_ENCODER_COUNTS,
+        }
+    }
+}
+
+impl From<pros_sys::motor_encoder_units_e_t> for MotorUnits {
+    fn from(value: pros_sys::motor_encoder_units_e_t) -> Self {
+        match value {
+            pros_sys::E_MOTOR_ENCODER_DEGREES => MotorUnits::Degrees,
+            pros_sys::E_MOTOR_ENCODER_ROTATIONS => MotorUnits::Rotations,
+            pros_sys::E_MOTOR_ENCODER_COUNTS => MotorUnits::Counts,
+            _ => unreachable!(),
+        }
+    }
+}
+
 /// Represents what the physical motor is currently doing.
 #[derive(Debug, Clone, Default)]
 pub struct MotorState {
@@ -296,6 +663,21 @@ impl From<u32> for MotorState {
     }
 }
 
+bitflags::bitflags! {
+    /// Faults that a motor can experience while operating.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct MotorFaults: u32 {
+        /// The motor's temperature is above its limit.
+        const OVER_TEMP = pros_sys::E_MOTOR_FAULT_MOTOR_OVER_TEMP;
+        /// The motor is drawing more current than its limit.
+        const OVER_CURRENT = pros_sys::E_MOTOR_FAULT_OVER_CURRENT;
+        /// The motor's H-bridge has encountered a fault.
+        const DRIVER_FAULT = pros_sys::E_MOTOR_FAULT_DRIVER_FAULT;
+        /// The motor's H-bridge is over current.
+        const H_BRIDGE_FAULT = pros_sys::E_MOTOR_FAULT_DRV_OVER_CURRENT;
+    }
+}
+
 /// Internal gearset used by VEX smart motors.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(i32)]
@@ -319,6 +701,33 @@ impl Gearset {
     pub const RPM_200: Gearset = Gearset::Green;
     /// 600 rpm
     pub const RPM_600: Gearset = Gearset::Blue;
+
+    /// Returns the number of encoder ticks in one revolution of the motor's output shaft.
+    pub const fn ticks_per_revolution(&self) -> f64 {
+        match self {
+            Self::Red => 1800.0,
+            Self::Green => 900.0,
+            Self::Blue => 300.0,
+        }
+    }
+
+    /// Returns the free speed of the motor's output shaft in RPM.
+    pub const fn max_rpm(&self) -> f64 {
+        match self {
+            Self::Red => 100.0,
+            Self::Green => 200.0,
+            Self::Blue => 600.0,
+        }
+    }
+
+    /// Returns the torque of the motor's output shaft in Nm when it is stalled at full power.
+    pub const fn stall_torque(&self) -> f64 {
+        match self {
+            Self::Red => 2.1,
+            Self::Green => 1.05,
+            Self::Blue => 0.35,
+        }
+    }
 }
 
 impl From<i32> for Gearset {
@@ -352,10 +761,45 @@ impl<'a> core::future::Future for MotorStoppedFuture<'a> {
     }
 }
 
+/// A future that completes once a motor has settled at its target position.
+///
+/// Created by [`Motor::wait_until_settled`] and [`Motor::wait_until_settled_timeout`].
+pub struct MotorSettledFuture<'a> {
+    motor: &'a Motor,
+    deadline: Option<Instant>,
+}
+
+impl<'a> core::future::Future for MotorSettledFuture<'a> {
+    type Output = Result<(), MotorError>;
+
+    fn poll(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<Self::Output> {
+        if self.motor.is_settled()? {
+            return core::task::Poll::Ready(Ok(()));
+        }
+
+        if self
+            .deadline
+            .is_some_and(|deadline| Instant::now() > deadline)
+        {
+            return core::task::Poll::Ready(Err(MotorError::SettleTimedOut));
+        }
+
+        cx.waker().wake_by_ref();
+        core::task::Poll::Pending
+    }
+}
+
 #[derive(Debug, Snafu)]
 pub enum MotorError {
     #[snafu(display("The voltage supplied was outside of the allowed range (-12 to 12)."))]
     VoltageOutOfRange,
+    #[snafu(display("The velocity supplied was faster than the motor's gearset allows."))]
+    VelocityOutOfRange,
+    #[snafu(display("The motor did not settle at its target before timing out."))]
+    SettleTimedOut,
     #[snafu(display("{source}"), context(false))]
     Port { source: PortError },
 }
@@ -364,3 +808,13 @@ map_errno! {
     MotorError {}
     inherit PortError;
 }
+
+#[derive(Debug, Snafu)]
+pub enum MotorGroupError {
+    #[snafu(display("A motor group must contain at least one motor."))]
+    Empty,
+    #[snafu(display("All motors in a motor group must use the same gearset."))]
+    GearsetMismatch,
+    #[snafu(display("{source}"), context(false))]
+    Motor { source: MotorError },
+}
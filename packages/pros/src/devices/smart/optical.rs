@@ -21,7 +21,7 @@ pub struct OpticalSensor {
 impl OpticalSensor {
     /// Creates a new inertial sensor from a smart port index.
     ///
-    /// Gesture detection features can be optionally enabled, allowing the use of [`Self::last_gesture_direction()`] and [`Self::last_gesture_direction()`].
+    /// Gesture detection features can be optionally enabled, allowing the use of [`Self::last_gesture_direction()`] and [`Self::last_gesture_raw()`].
     pub fn new(port: SmartPort, gesture_detection_enabled: bool) -> Result<Self, OpticalError> {
         let mut sensor = Self {
             port,
@@ -47,17 +47,13 @@ impl OpticalSensor {
         }
     }
 
-    /// Sets the pwm value of the White LED. Valid values are in the range `0` `100`.
+    /// Sets the pwm value of the White LED.
+    ///
+    /// Valid values are in the range `0` to `100`, and values above [`MAX_LED_PWM`] are clamped to `100`.
     pub fn set_led_pwm(&mut self, value: u8) -> Result<(), OpticalError> {
-        if value > MAX_LED_PWM {
-            return Err(OpticalError::InvalidLedPwm);
-        }
-        unsafe {
-            bail_on!(
-                PROS_ERR,
-                pros_sys::optical_set_led_pwm(self.port.index(), value)
-            );
-        }
+        bail_on!(PROS_ERR, unsafe {
+            pros_sys::optical_set_led_pwm(self.port.index(), value.min(MAX_LED_PWM))
+        });
         Ok(())
     }
 
@@ -77,20 +73,16 @@ impl OpticalSensor {
     /// Lower integration time results in faster update rates with lower accuracy
     /// due to less available light being read by the sensor.
     ///
-    /// Time value must be a [`Duration`] between 3 and 712 milliseconds. See
+    /// The hardware only supports integration times between [`MIN_INTEGRATION_TIME`] (3ms) and
+    /// [`MAX_INTEGRATION_TIME`] (712ms), so durations outside of this range are clamped. See
     /// https://www.vexforum.com/t/v5-optical-sensor-refresh-rate/109632/9 for
     /// more information.
     pub fn set_integration_time(&mut self, time: Duration) -> Result<(), OpticalError> {
-        if time < MIN_INTEGRATION_TIME || time > MAX_INTEGRATION_TIME {
-            return Err(OpticalError::InvalidIntegrationTime);
-        }
+        let time = time.clamp(MIN_INTEGRATION_TIME, MAX_INTEGRATION_TIME);
 
-        unsafe {
-            bail_on!(
-                PROS_ERR,
-                pros_sys::optical_set_integration_time(self.port.index(), time.as_millis() as f64)
-            );
-        }
+        bail_on!(PROS_ERR, unsafe {
+            pros_sys::optical_set_integration_time(self.port.index(), time.as_millis() as f64)
+        });
 
         Ok(())
     }
@@ -133,7 +125,8 @@ impl OpticalSensor {
 
     /// Get the detected proximity value
     ///
-    /// Proximity has a range of `0` to `255`.
+    /// Proximity has a range of `0` to `255`, with higher values meaning that
+    /// the detected object is closer to the sensor.
     pub fn proximity(&self) -> Result<i32, OpticalError> {
         unsafe {
             Ok(bail_on!(
@@ -143,19 +136,22 @@ impl OpticalSensor {
         }
     }
 
-    /// Get the processed RGBC data from the sensor
+    /// Get the processed RGBC data from the sensor.
+    ///
+    /// Unlike [`Self::hue`], [`Self::saturation`], and [`Self::brightness`], this preserves
+    /// the individual color channels read by the sensor.
     pub fn rgbc(&self) -> Result<Rgbc, OpticalError> {
         unsafe { pros_sys::optical_get_rgb(self.port.index()).try_into() }
     }
 
-    /// Get the raw, unprocessed RGBC data from the sensor
+    /// Get the raw, unprocessed RGBC data from the sensor.
     pub fn rgbc_raw(&self) -> Result<RgbcRaw, OpticalError> {
         unsafe { pros_sys::optical_get_raw(self.port.index()).try_into() }
     }
 
     /// Enables gesture detection features on the sensor.
     ///
-    /// This allows [`Self::last_gesture_direction()`] and [`Self::last_gesture_direction()`] to be called without error, if
+    /// This allows [`Self::last_gesture_direction()`] and [`Self::last_gesture_raw()`] to be called without error, if
     /// gesture detection wasn't already enabled.
     pub fn enable_gesture_detection(&mut self) -> Result<(), OpticalError> {
         bail_on!(PROS_ERR, unsafe {
@@ -183,8 +179,8 @@ impl OpticalSensor {
 
     /// Get the most recent gesture data from the sensor. Gestures will be cleared after 500mS.
     ///
-    /// Will return [`OpticalError::GestureDetectionNotEnabled`] if the sensor is not
-    /// confgured to detect gestures.
+    /// Will return [`OpticalError::GestureDetectionDisabled`] if the sensor is not
+    /// configured to detect gestures.
     pub fn last_gesture_direction(&self) -> Result<GestureDirection, OpticalError> {
         if !self.gesture_detection_enabled {
             return Err(OpticalError::GestureDetectionDisabled);
@@ -195,8 +191,11 @@ impl OpticalSensor {
 
     /// Get the most recent raw gesture data from the sensor.
     ///
-    /// Will return [`OpticalError::GestureDetectionNotEnabled`] if the sensor is not
-    /// confgured to detect gestures.
+    /// Unlike [`Self::last_gesture_direction()`], this returns the full data reported by
+    /// the gesture engine, which can be used for things such as measuring swipe speed.
+    ///
+    /// Will return [`OpticalError::GestureDetectionDisabled`] if the sensor is not
+    /// configured to detect gestures.
     pub fn last_gesture_raw(&self) -> Result<GestureRaw, OpticalError> {
         if !self.gesture_detection_enabled {
             return Err(OpticalError::GestureDetectionDisabled);
@@ -216,6 +215,7 @@ impl SmartDevice for OpticalSensor {
     }
 }
 
+/// The direction of a gesture detected by an optical sensor.
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
 pub enum GestureDirection {
     Up,
@@ -244,14 +244,22 @@ impl TryFrom<pros_sys::optical_direction_e_t> for GestureDirection {
     }
 }
 
+/// Raw gesture data reported by an optical sensor's gesture engine.
 #[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
 pub struct GestureRaw {
+    /// Up data.
     pub up: u8,
+    /// Down data.
     pub down: u8,
+    /// Left data.
     pub left: u8,
+    /// Right data.
     pub right: u8,
+    /// The type of the detected gesture.
     pub gesture_type: u8,
+    /// The number of gestures detected.
     pub count: u16,
+    /// The time of the gesture in milliseconds.
     pub time: u32,
 }
 
@@ -271,11 +279,16 @@ impl TryFrom<pros_sys::optical_gesture_s_t> for GestureRaw {
     }
 }
 
+/// Processed RGB color data read from an optical sensor.
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
 pub struct Rgbc {
+    /// The red channel of the detected color.
     pub red: f64,
+    /// The green channel of the detected color.
     pub green: f64,
+    /// The blue channel of the detected color.
     pub blue: f64,
+    /// The overall brightness of the detected color.
     pub brightness: f64,
 }
 
@@ -292,11 +305,16 @@ impl TryFrom<pros_sys::optical_rgb_s_t> for Rgbc {
     }
 }
 
+/// Raw, unprocessed RGBC color data read from an optical sensor.
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct RgbcRaw {
+    /// The raw red channel reading.
     pub red: u32,
+    /// The raw green channel reading.
     pub green: u32,
+    /// The raw blue channel reading.
     pub blue: u32,
+    /// The raw clear (unfiltered) channel reading.
     pub clear: u32,
 }
 
@@ -315,12 +333,6 @@ impl TryFrom<pros_sys::optical_raw_s_t> for RgbcRaw {
 
 #[derive(Debug, Snafu)]
 pub enum OpticalError {
-    #[snafu(display("LED PWM value must be between 0 and 100."))]
-    InvalidLedPwm,
-
-    #[snafu(display("Integration time must be between 3 and 712 milliseconds. See https://www.vexforum.com/t/v5-optical-sensor-refresh-rate/109632/9 for more information."))]
-    InvalidIntegrationTime,
-
     #[snafu(display("Gesture detection is not enabled for this sensor."))]
     GestureDetectionDisabled,
 
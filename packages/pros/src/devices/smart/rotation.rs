@@ -2,19 +2,23 @@
 //!
 //! Rotation sensors operate on the same [`Position`] type as motors to measure rotation.
 
+use core::time::Duration;
+
 use pros_sys::PROS_ERR;
 
-use super::{SmartDevice, SmartDeviceType, SmartPort};
+use super::{round_data_rate, SmartDevice, SmartDeviceType, SmartPort};
 use crate::{
     devices::position::Position,
     error::{bail_on, PortError},
 };
 
+/// The minimum data rate supported by the rotation sensor.
+pub const ROTATION_MIN_DATA_RATE: Duration = Duration::from_millis(5);
+
 /// A physical rotation sensor plugged into a port.
 #[derive(Debug, Eq, PartialEq)]
 pub struct RotationSensor {
     port: SmartPort,
-    pub reversed: bool,
 }
 
 impl RotationSensor {
@@ -31,7 +35,7 @@ impl RotationSensor {
             }
         }
 
-        Ok(Self { port, reversed })
+        Ok(Self { port })
     }
 
     /// Sets the position to zero.
@@ -61,8 +65,6 @@ impl RotationSensor {
 
     /// Sets whether or not the rotation sensor should be reversed.
     pub fn set_reversed(&mut self, reversed: bool) -> Result<(), PortError> {
-        self.reversed = reversed;
-
         unsafe {
             bail_on!(
                 PROS_ERR,
@@ -72,9 +74,16 @@ impl RotationSensor {
         Ok(())
     }
 
-    /// Reverses the rotation sensor.
+    /// Reverses the rotation sensor, flipping its current direction.
     pub fn reverse(&mut self) -> Result<(), PortError> {
-        self.set_reversed(!self.reversed)
+        self.set_reversed(!self.reversed()?)
+    }
+
+    /// Returns whether or not the rotation sensor is reversed.
+    pub fn reversed(&self) -> Result<bool, PortError> {
+        Ok(bail_on!(PROS_ERR, unsafe {
+            pros_sys::rotation_get_reversed(self.port.index())
+        }) == 1)
     }
 
     //TODO: See if this is accurate enough or consider switching to get_position function.
@@ -86,6 +95,28 @@ impl RotationSensor {
             )
         })
     }
+
+    /// Gets the current velocity of the sensor in degrees per second.
+    pub fn velocity(&self) -> Result<f64, PortError> {
+        Ok(bail_on!(PROS_ERR, unsafe {
+            pros_sys::rotation_get_velocity(self.port.index())
+        }) as f64
+            / 100.0)
+    }
+
+    /// Sets the update rate of the sensor.
+    ///
+    /// The sensor only supports data rates in multiples of [`ROTATION_MIN_DATA_RATE`] (5 milliseconds),
+    /// so the given duration is rounded to the nearest multiple. Durations below the minimum
+    /// are clamped to 5 milliseconds.
+    pub fn set_data_rate(&mut self, data_rate: Duration) -> Result<(), PortError> {
+        let rate_ms = round_data_rate(data_rate, ROTATION_MIN_DATA_RATE);
+
+        bail_on!(PROS_ERR, unsafe {
+            pros_sys::rotation_set_data_rate(self.port.index(), rate_ms)
+        });
+        Ok(())
+    }
 }
 
 impl SmartDevice for RotationSensor {
@@ -0,0 +1,179 @@
+//! Generic serial communication over a smart port.
+//!
+//! Smart ports can be used as a generic serial port with a [`SerialPort`],
+//! which is useful for communicating with custom hardware such as a coprocessor.
+//! [`SerialPort`] implements a no_std version of `Read` and `Write` from [`no_std_io`].
+//!
+//! Data is buffered in the port's input and output FIFO buffers.
+//! Writes are sent as soon as possible and reads never block waiting for data to arrive.
+
+use no_std_io::io;
+use pros_sys::PROS_ERR;
+use snafu::Snafu;
+
+use super::{SmartDevice, SmartDeviceType, SmartPort};
+use crate::error::{bail_on, map_errno, PortError};
+
+/// A smart port configured as a generic serial port.
+#[derive(Debug, Eq, PartialEq)]
+pub struct SerialPort {
+    port: SmartPort,
+}
+
+impl SerialPort {
+    /// Enables generic serial on a smart port, operating at the given baudrate.
+    pub fn open(port: SmartPort, baudrate: u32) -> Result<Self, SerialError> {
+        unsafe {
+            bail_on!(PROS_ERR, pros_sys::serial_enable(port.index()));
+        }
+
+        let mut serial = Self { port };
+        serial.set_baudrate(baudrate)?;
+
+        Ok(serial)
+    }
+
+    /// Sets the baudrate that the serial port operates at.
+    pub fn set_baudrate(&mut self, baudrate: u32) -> Result<(), SerialError> {
+        unsafe {
+            bail_on!(
+                PROS_ERR,
+                pros_sys::serial_set_baudrate(self.port.index(), baudrate as i32)
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Clears the input and output buffers, discarding any data that has not been read or sent.
+    pub fn clear_buffers(&mut self) -> Result<(), SerialError> {
+        unsafe {
+            bail_on!(PROS_ERR, pros_sys::serial_flush(self.port.index()));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of bytes available to be read from the input buffer.
+    pub fn bytes_to_read(&self) -> Result<usize, SerialError> {
+        Ok(
+            unsafe { bail_on!(PROS_ERR, pros_sys::serial_get_read_avail(self.port.index())) }
+                as usize,
+        )
+    }
+
+    /// Returns the number of bytes of free space in the output buffer.
+    pub fn available_write_bytes(&self) -> Result<usize, SerialError> {
+        Ok(
+            unsafe { bail_on!(PROS_ERR, pros_sys::serial_get_write_free(self.port.index())) }
+                as usize,
+        )
+    }
+
+    /// Reads the next byte in the input buffer without removing it.
+    ///
+    /// Returns `None` if no bytes are available.
+    pub fn peek_byte(&self) -> Result<Option<u8>, SerialError> {
+        let byte = unsafe { bail_on!(PROS_ERR, pros_sys::serial_peek_byte(self.port.index())) };
+
+        Ok(u8::try_from(byte).ok())
+    }
+
+    /// Reads the next byte in the input buffer.
+    ///
+    /// Returns `None` if no bytes are available.
+    pub fn read_byte(&mut self) -> Result<Option<u8>, SerialError> {
+        let byte = unsafe { bail_on!(PROS_ERR, pros_sys::serial_read_byte(self.port.index())) };
+
+        Ok(u8::try_from(byte).ok())
+    }
+
+    /// Writes a single byte to the output buffer.
+    ///
+    /// Returns `false` if the output buffer is full.
+    pub fn write_byte(&mut self, byte: u8) -> Result<bool, SerialError> {
+        let written = unsafe {
+            bail_on!(
+                PROS_ERR,
+                pros_sys::serial_write_byte(self.port.index(), byte)
+            )
+        };
+
+        Ok(written != 0)
+    }
+
+    /// Reads as many bytes as are available into the given buffer, returning the number of bytes read.
+    ///
+    /// This does not wait for data to arrive.
+    pub fn read_bytes(&mut self, buf: &mut [u8]) -> Result<usize, SerialError> {
+        Ok(unsafe {
+            bail_on!(
+                PROS_ERR,
+                pros_sys::serial_read(self.port.index(), buf.as_mut_ptr(), buf.len() as i32)
+            )
+        } as usize)
+    }
+
+    /// Writes as many bytes as fit in the output buffer, returning the number of bytes written.
+    pub fn write_bytes(&mut self, buf: &[u8]) -> Result<usize, SerialError> {
+        Ok(unsafe {
+            bail_on!(
+                PROS_ERR,
+                pros_sys::serial_write(
+                    self.port.index(),
+                    buf.as_ptr().cast_mut(),
+                    buf.len() as i32
+                )
+            )
+        } as usize)
+    }
+}
+
+impl io::Read for SerialPort {
+    fn read(&mut self, dst: &mut [u8]) -> io::Result<usize> {
+        self.read_bytes(dst)
+            .map_err(|_| io::Error::new(io::ErrorKind::Other, "failed to read from serial port"))
+    }
+}
+
+impl io::Write for SerialPort {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_bytes(buf)
+            .map_err(|_| io::Error::new(io::ErrorKind::Other, "failed to write to serial port"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        // The output buffer is sent as soon as possible, so there is nothing to flush.
+        Ok(())
+    }
+}
+
+impl SmartDevice for SerialPort {
+    fn port_index(&self) -> u8 {
+        self.port.index()
+    }
+
+    fn device_type(&self) -> SmartDeviceType {
+        SmartDeviceType::Serial
+    }
+}
+
+#[derive(Debug, Snafu)]
+pub enum SerialError {
+    #[snafu(display("Another resource is currently trying to access the serial port."))]
+    Busy,
+    #[snafu(display("A serious internal write error occurred."))]
+    Internal,
+    #[snafu(display("{source}"), context(false))]
+    Port { source: PortError },
+}
+
+map_errno! {
+    SerialError {
+        EACCES => Self::Busy,
+        EIO => Self::Internal,
+        // PROS uses EINVAL rather than ENXIO for ports outside of 1-21.
+        EINVAL => Self::Port { source: PortError::PortOutOfRange },
+    }
+    inherit PortError;
+}
@@ -2,9 +2,6 @@
 //!
 //! Vision sensors take in a zero point at creation.
 
-extern crate alloc;
-use alloc::vec::Vec;
-
 use pros_sys::{PROS_ERR, VISION_OBJECT_ERR_SIG};
 use snafu::Snafu;
 
@@ -38,26 +35,86 @@ impl VisionSensor {
         unsafe { pros_sys::vision_get_by_size(self.port.index(), n).try_into() }
     }
 
-    /// Returns a list of all objects in order of size (largest to smallest).
-    pub fn objects(&self) -> Result<Vec<VisionObject>, VisionError> {
-        let obj_count = self.num_objects()?;
-        let mut objects_buf = Vec::with_capacity(obj_count);
+    /// Returns the largest object seen by the camera.
+    pub fn largest_object(&self) -> Result<VisionObject, VisionError> {
+        self.nth_largest_object(0)
+    }
 
-        unsafe {
-            pros_sys::vision_read_by_size(
+    /// Returns an iterator over all objects in order of size (largest to smallest).
+    ///
+    /// Each object is read from the sensor as the iterator reaches it. If the sensor sees
+    /// fewer objects by then, the iterator ends early.
+    pub fn objects(&self) -> Result<impl Iterator<Item = VisionObject> + '_, VisionError> {
+        let obj_count = self.num_objects()? as u32;
+        Ok((0..obj_count).map_while(|n| self.nth_largest_object(n).ok()))
+    }
+
+    /// Returns all objects matching the given color code in order of size (largest to smallest).
+    pub fn objects_by_code(
+        &self,
+        code: VisionColorCode,
+    ) -> Result<impl Iterator<Item = VisionObject> + '_, VisionError> {
+        let obj_count = self.num_objects()? as u32;
+        Ok((0..obj_count).map_while(move |n| unsafe {
+            pros_sys::vision_get_by_code(self.port.index(), n, code.0)
+                .try_into()
+                .ok()
+        }))
+    }
+
+    /// Creates a color code from 2 to 5 signature IDs (1-7).
+    ///
+    /// Color codes detect objects made up of multiple signatures placed next to each other,
+    /// in the order they are given.
+    pub fn create_color_code(&mut self, signatures: &[u8]) -> Result<VisionColorCode, VisionError> {
+        if !(2..=5).contains(&signatures.len()) || signatures.iter().any(|id| !(1..=7).contains(id))
+        {
+            return Err(VisionError::InvalidColorCode);
+        }
+
+        let mut ids = [0; 5];
+        for (id, signature) in ids.iter_mut().zip(signatures) {
+            *id = *signature as u32;
+        }
+
+        Ok(VisionColorCode(bail_on!(VISION_OBJECT_ERR_SIG, unsafe {
+            pros_sys::vision_create_color_code(
                 self.port.index(),
-                0,
-                obj_count as _,
-                objects_buf.as_mut_ptr(),
-            );
+                ids[0],
+                ids[1],
+                ids[2],
+                ids[3],
+                ids[4],
+            )
+        })))
+    }
+
+    /// Stores a color signature on the vision sensor with the given ID (1-7).
+    ///
+    /// Signatures are stored in volatile memory, and will be lost once the sensor is powered down.
+    pub fn set_signature(
+        &mut self,
+        id: u8,
+        signature: &VisionSignature,
+    ) -> Result<(), VisionError> {
+        if !(1..=7).contains(&id) {
+            return Err(VisionError::InvalidSignatureId);
         }
 
-        bail_errno!();
+        let signature = signature.into_raw(id);
+        bail_on!(PROS_ERR, unsafe {
+            pros_sys::vision_set_signature(self.port.index(), id, &signature)
+        });
+        Ok(())
+    }
+
+    /// Returns the color signature stored on the vision sensor with the given ID (1-7).
+    pub fn signature(&self, id: u8) -> Result<VisionSignature, VisionError> {
+        if !(1..=7).contains(&id) {
+            return Err(VisionError::InvalidSignatureId);
+        }
 
-        Ok(objects_buf
-            .into_iter()
-            .filter_map(|object| object.try_into().ok())
-            .collect())
+        unsafe { pros_sys::vision_get_signature(self.port.index(), id).try_into() }
     }
 
     /// Returns the number of objects seen by the camera.
@@ -73,57 +130,80 @@ impl VisionSensor {
     }
 
     /// Get the current exposure percentage of the vision sensor. The returned result should be within 0.0 to 1.5.
-    pub fn exposure(&self) -> f32 {
-        unsafe { (pros_sys::vision_get_exposure(self.port.index()) as f32) * 1.5 / 150.0 }
+    pub fn exposure(&self) -> Result<f32, VisionError> {
+        let exposure = bail_on!(PROS_ERR, unsafe {
+            pros_sys::vision_get_exposure(self.port.index())
+        });
+        Ok(exposure as f32 * 1.5 / 150.0)
     }
 
     /// Get the current white balance of the vision sensor.
-    pub fn current_white_balance(&self) -> Rgb {
-        unsafe { (pros_sys::vision_get_white_balance(self.port.index()) as u32).into() }
+    pub fn current_white_balance(&self) -> Result<Rgb, VisionError> {
+        let rgb = bail_on!(PROS_ERR, unsafe {
+            pros_sys::vision_get_white_balance(self.port.index())
+        });
+        Ok((rgb as u32).into())
     }
 
     /// Sets the exposure percentage of the vision sensor. Should be between 0.0 and 1.5.
-    pub fn set_exposure(&mut self, exposure: f32) {
-        unsafe {
-            pros_sys::vision_set_exposure(self.port.index(), (exposure * 150.0 / 1.5) as u8);
-        }
+    ///
+    /// Values outside of this range are clamped.
+    pub fn set_exposure(&mut self, exposure: f32) -> Result<(), VisionError> {
+        let exposure = exposure.clamp(0.0, 1.5);
+        bail_on!(PROS_ERR, unsafe {
+            pros_sys::vision_set_exposure(self.port.index(), (exposure * 150.0 / 1.5) as u8)
+        });
+        Ok(())
     }
 
     /// Sets the white balance of the vision sensor.
-    pub fn set_white_balance(&mut self, white_balance: WhiteBalance) {
-        unsafe {
-            match white_balance {
-                WhiteBalance::Auto => pros_sys::vision_set_auto_white_balance(self.port.index(), 1),
-                WhiteBalance::Rgb(rgb) => {
-                    // Turn off automatic white balance
-                    pros_sys::vision_set_auto_white_balance(self.port.index(), 0);
+    pub fn set_white_balance(&mut self, white_balance: WhiteBalance) -> Result<(), VisionError> {
+        match white_balance {
+            WhiteBalance::Auto => {
+                bail_on!(PROS_ERR, unsafe {
+                    pros_sys::vision_set_auto_white_balance(self.port.index(), 1)
+                });
+            }
+            WhiteBalance::Rgb(rgb) => {
+                // Turn off automatic white balance
+                bail_on!(PROS_ERR, unsafe {
+                    pros_sys::vision_set_auto_white_balance(self.port.index(), 0)
+                });
+                bail_on!(PROS_ERR, unsafe {
                     pros_sys::vision_set_white_balance(
                         self.port.index(),
                         <Rgb as Into<u32>>::into(rgb) as i32,
                     )
-                }
-            };
+                });
+            }
         }
+        Ok(())
     }
 
     /// Sets the point that object positions are relative to, in other words where (0, 0) is or the zero point.
-    pub fn set_zero_point(&mut self, zero: VisionZeroPoint) {
-        unsafe {
-            pros_sys::vision_set_zero_point(self.port.index(), zero as _);
-        }
+    pub fn set_zero_point(&mut self, zero: VisionZeroPoint) -> Result<(), VisionError> {
+        bail_on!(PROS_ERR, unsafe {
+            pros_sys::vision_set_zero_point(self.port.index(), zero as _)
+        });
+        Ok(())
     }
 
     /// Sets the color of the led.
-    pub fn set_led(&mut self, mode: LedMode) {
-        unsafe {
-            match mode {
-                LedMode::Off => pros_sys::vision_clear_led(self.port.index()),
-                LedMode::On(rgb) => pros_sys::vision_set_led(
-                    self.port.index(),
-                    <Rgb as Into<u32>>::into(rgb) as i32,
-                ),
-            };
-        }
+    pub fn set_led(&mut self, mode: LedMode) -> Result<(), VisionError> {
+        match mode {
+            LedMode::Off => bail_on!(PROS_ERR, unsafe {
+                pros_sys::vision_clear_led(self.port.index())
+            }),
+            LedMode::On(rgb) => bail_on!(PROS_ERR, unsafe {
+                pros_sys::vision_set_led(self.port.index(), <Rgb as Into<u32>>::into(rgb) as i32)
+            }),
+        };
+        Ok(())
+    }
+
+    /// Turns off the led, returning it to its default behavior.
+    pub fn clear_led(&mut self) -> Result<(), VisionError> {
+        self.set_led(LedMode::Off)
     }
 }
 
@@ -138,8 +218,14 @@ impl SmartDevice for VisionSensor {
 }
 
 //TODO: figure out how coordinates are done.
+/// An object detected by the vision sensor.
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct VisionObject {
+    /// The ID of the signature (or color code) used to detect this object.
+    pub signature: u16,
+    /// The angle of the object, only applicable to color code objects.
+    pub angle: i16,
+
     pub top: i16,
     pub left: i16,
     pub middle_x: i16,
@@ -158,6 +244,8 @@ impl TryFrom<pros_sys::vision_object_s_t> for VisionObject {
         }
 
         Ok(Self {
+            signature: value.signature,
+            angle: value.angle,
             top: value.top_coord,
             left: value.left_coord,
             middle_x: value.x_middle_coord,
@@ -168,6 +256,108 @@ impl TryFrom<pros_sys::vision_object_s_t> for VisionObject {
     }
 }
 
+/// A combination of signatures used by the vision sensor to detect multi-colored objects.
+///
+/// Created by [`VisionSensor::create_color_code`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VisionColorCode(u16);
+
+impl VisionColorCode {
+    /// Returns the raw color code used by the vision sensor.
+    ///
+    /// This is the same value as the [`VisionObject::signature`] of objects detected with this color code.
+    pub const fn raw(&self) -> u16 {
+        self.0
+    }
+}
+
+/// A color signature used by the vision sensor to detect objects.
+///
+/// Signatures are most easily created by copying the numbers generated by the
+/// VEX Vision Utility into [`VisionSignature::from_utility`].
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct VisionSignature {
+    /// Minimum value on the U axis.
+    pub u_min: i32,
+    /// Maximum value on the U axis.
+    pub u_max: i32,
+    /// Mean value on the U axis.
+    pub u_mean: i32,
+    /// Minimum value on the V axis.
+    pub v_min: i32,
+    /// Maximum value on the V axis.
+    pub v_max: i32,
+    /// Mean value on the V axis.
+    pub v_mean: i32,
+    /// The scale factor of the signature.
+    pub range: f32,
+    /// The color used to display the signature in the Vision Utility.
+    pub rgb: Rgb,
+}
+
+impl VisionSignature {
+    /// Creates a signature from the values generated by the VEX Vision Utility.
+    ///
+    /// The utility outputs signatures in the form
+    /// `SIG_1 (1, u_min, u_max, u_mean, v_min, v_max, v_mean, range, 0)`, where the first
+    /// number is the signature ID passed to [`VisionSensor::set_signature`].
+    pub const fn from_utility(
+        u_min: i32,
+        u_max: i32,
+        u_mean: i32,
+        v_min: i32,
+        v_max: i32,
+        v_mean: i32,
+        range: f32,
+    ) -> Self {
+        Self {
+            u_min,
+            u_max,
+            u_mean,
+            v_min,
+            v_max,
+            v_mean,
+            range,
+            rgb: Rgb::new(0, 0, 0),
+        }
+    }
+
+    fn into_raw(self, id: u8) -> pros_sys::vision_signature_s_t {
+        pros_sys::vision_signature_s_t {
+            id,
+            _pad: [0; 3],
+            range: self.range,
+            u_min: self.u_min,
+            u_max: self.u_max,
+            u_mean: self.u_mean,
+            v_min: self.v_min,
+            v_max: self.v_max,
+            v_mean: self.v_mean,
+            rgb: self.rgb.into(),
+            r#type: pros_sys::E_VISION_OBJECT_NORMAL,
+        }
+    }
+}
+
+impl TryFrom<pros_sys::vision_signature_s_t> for VisionSignature {
+    type Error = VisionError;
+
+    fn try_from(value: pros_sys::vision_signature_s_t) -> Result<VisionSignature, VisionError> {
+        bail_on!(VISION_OBJECT_ERR_SIG as u8, value.id);
+
+        Ok(Self {
+            u_min: value.u_min,
+            u_max: value.u_max,
+            u_mean: value.u_mean,
+            v_min: value.v_min,
+            v_max: value.v_max,
+            v_mean: value.v_mean,
+            range: value.range,
+            rgb: value.rgb.into(),
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Rgb {
     r: u8,
@@ -176,7 +366,7 @@ pub struct Rgb {
 }
 
 impl Rgb {
-    pub fn new(r: u8, g: u8, b: u8) -> Self {
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
         Self { r, g, b }
     }
 }
@@ -227,37 +417,48 @@ pub enum VisionZeroPoint {
     Center,
 }
 
+/// White balance modes for the vision sensor.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WhiteBalance {
+    /// Manual white balance using the given color.
     Rgb(Rgb),
+    /// Automatic white balance.
     Auto,
 }
 
+/// Modes for the vision sensor's led.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LedMode {
+    /// The led is set to the given color.
     On(Rgb),
+    /// The led is controlled by the sensor, which uses it to show the most prominent detected object.
     Off,
 }
 
 #[derive(Debug, Snafu)]
 pub enum VisionError {
+    #[snafu(display("The camera could not be read."))]
+    ReadingFailed,
     #[snafu(display(
         "The index specified was higher than the total number of objects seen by the camera."
     ))]
-    ReadingFailed,
-    #[snafu(display("The camera could not be read."))]
     IndexTooHigh,
     #[snafu(display("Port already taken."))]
     PortTaken,
+    #[snafu(display("Signature IDs must be between 1 and 7."))]
+    InvalidSignatureId,
+    #[snafu(display("Color codes must contain 2 to 5 signature IDs between 1 and 7."))]
+    InvalidColorCode,
     #[snafu(display("{source}"), context(false))]
     Port { source: PortError },
 }
 
 map_errno! {
     VisionError {
-        EHOSTDOWN => Self::ReadingFailed,
+        EHOSTDOWN | EAGAIN => Self::ReadingFailed,
         EDOM => Self::IndexTooHigh,
         EACCES => Self::PortTaken,
+        EINVAL => Self::InvalidColorCode,
     }
     inherit PortError;
 }
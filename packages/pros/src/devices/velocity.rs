@@ -0,0 +1,125 @@
+//! Generic angular velocity type for motors and sensors.
+//!
+//! Velocities can be converted between RPM, radians per second, degrees per second,
+//! and the encoder ticks per second of a motor with a given [`Gearset`].
+
+use core::{f64::consts::PI, ops::*};
+
+use super::smart::motor::Gearset;
+
+/// Represents an angular velocity.
+///
+/// ```ignore
+/// let speed = motor.velocity()?.into_radians_per_second() * wheel_radius;
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Velocity {
+    rpm: f64,
+}
+
+impl Velocity {
+    /// Creates a velocity from a specified number of rotations per minute.
+    pub const fn from_rpm(rpm: f64) -> Self {
+        Self { rpm }
+    }
+
+    /// Creates a velocity from a specified number of degrees per second.
+    pub fn from_degrees_per_second(velocity: f64) -> Self {
+        Self::from_rpm(velocity * 60.0 / 360.0)
+    }
+
+    /// Creates a velocity from a specified number of radians per second.
+    pub fn from_radians_per_second(velocity: f64) -> Self {
+        Self::from_rpm(velocity * 60.0 / (2.0 * PI))
+    }
+
+    /// Creates a velocity from a specified number of encoder ticks per second
+    /// of a motor with the given gearset.
+    pub fn from_ticks_per_second(velocity: f64, gearset: Gearset) -> Self {
+        Self::from_rpm(velocity * 60.0 / gearset.ticks_per_revolution())
+    }
+
+    /// Converts a velocity into rotations per minute.
+    pub fn into_rpm(self) -> f64 {
+        self.rpm
+    }
+
+    /// Converts a velocity into degrees per second.
+    pub fn into_degrees_per_second(self) -> f64 {
+        self.rpm * 360.0 / 60.0
+    }
+
+    /// Converts a velocity into radians per second.
+    pub fn into_radians_per_second(self) -> f64 {
+        self.rpm * 2.0 * PI / 60.0
+    }
+
+    /// Converts a velocity into encoder ticks per second of a motor with the given gearset.
+    pub fn into_ticks_per_second(self, gearset: Gearset) -> f64 {
+        self.rpm * gearset.ticks_per_revolution() / 60.0
+    }
+}
+
+impl Add for Velocity {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_rpm(self.rpm + rhs.rpm)
+    }
+}
+
+impl AddAssign for Velocity {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Velocity {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::from_rpm(self.rpm - rhs.rpm)
+    }
+}
+
+impl SubAssign for Velocity {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<f64> for Velocity {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::from_rpm(self.rpm * rhs)
+    }
+}
+
+impl MulAssign<f64> for Velocity {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div<f64> for Velocity {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        Self::from_rpm(self.rpm / rhs)
+    }
+}
+
+impl DivAssign<f64> for Velocity {
+    fn div_assign(&mut self, rhs: f64) {
+        *self = *self / rhs;
+    }
+}
+
+impl Neg for Velocity {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::from_rpm(-self.rpm)
+    }
+}
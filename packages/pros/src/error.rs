@@ -15,6 +15,8 @@ pub(crate) fn take_errno() -> i32 {
 
 /// Generate an implementation of FromErrno for the given type.
 ///
+/// Errno values mapped by the type take precedence over those mapped by the inherited type.
+///
 /// Example:
 /// ```ignore
 /// map_errno! {
@@ -33,18 +35,19 @@ macro_rules! map_errno {
             fn from_errno(num: i32) -> Option<Self> {
                 #[allow(unused_imports)]
                 use pros_sys::error::*;
+                match num {
+                    $($errno => return Some($err),)*
+                    // this function should only be called if errno is set
+                    0 => panic!("Expected error state in errno, found 0."),
+                    _ => {}
+                }
                 $(
                     // if the enum we're inheriting from can handle this errno, return it.
                     if let Some(err) = <$base as $crate::error::FromErrno>::from_errno(num) {
                         return Some(err.into());
                     }
                 )?
-                match num {
-                    $($errno => Some($err),)*
-                    // this function should only be called if errno is set
-                    0 => panic!("Expected error state in errno, found 0."),
-                    _ => None,
-                }
+                None
             }
         }
     }
@@ -82,6 +85,8 @@ macro_rules! bail_on {
 pub(crate) use bail_on;
 use snafu::Snafu;
 
+use crate::devices::smart::SmartDeviceType;
+
 pub trait FromErrno {
     /// Consume the current `errno` and, if it contains a known error, returns Self.
     fn from_errno(num: i32) -> Option<Self>
@@ -93,14 +98,25 @@ pub trait FromErrno {
 pub enum PortError {
     #[snafu(display("The port you specified is outside of the allowed range!"))]
     PortOutOfRange,
-    #[snafu(display(
-        // used to have "Is something else plugged in?" But the vex radio (link) uses the same errno, so that's not always applicable.
-        "The port you specified couldn't be configured as what you specified."
-    ))]
-    PortCannotBeConfigured,
+    /// The device on the port was unplugged or replaced with a different device.
+    ///
+    /// This is usually transient, and the operation can be retried once the device is reconnected.
+    #[snafu(display("The device on the port you specified is not connected."))]
+    PortNotConnected,
+    /// The port is already configured as a different type of device.
+    ///
+    /// This is usually caused by a wiring mistake, so retrying will not help.
+    #[snafu(display("The port you specified is configured as a different type of device."))]
+    IncorrectDevice,
+    #[snafu(display("Expected a {expected:?} device on the port, but found {found:?}."))]
+    DeviceTypeMismatch {
+        expected: SmartDeviceType,
+        found: SmartDeviceType,
+    },
 }
 
 map_errno!(PortError {
     ENXIO => Self::PortOutOfRange,
-    ENODEV => Self::PortCannotBeConfigured,
+    ENODEV => Self::PortNotConnected,
+    EADDRINUSE => Self::IncorrectDevice,
 });
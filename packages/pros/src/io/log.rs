@@ -0,0 +1,121 @@
+//! Timestamped, leveled logging.
+//!
+//! The [`info!`](crate::info), [`warn!`](crate::warn), and [`error!`](crate::error) macros
+//! print a line to the terminal prefixed with the time since the program started in milliseconds,
+//! the level of the message, and the name of the current task:
+//!
+//! ```text
+//! [    1520ms INFO  opcontrol] Intake jammed, reversing
+//! ```
+//!
+//! Messages less severe than the level set with [`set_max_level`] are discarded,
+//! which allows verbose logging to be turned off at runtime.
+
+use core::{
+    fmt,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+use super::print_impl::__SerialWriter;
+
+/// The severity of a log message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum Level {
+    /// A failure that the program could not recover from.
+    Error = 1,
+    /// Something unexpected that the program recovered from.
+    Warn = 2,
+    /// General information about what the program is doing.
+    Info = 3,
+}
+
+impl Level {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Error => "ERROR",
+            Self::Warn => "WARN",
+            Self::Info => "INFO",
+        }
+    }
+
+    const fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(Self::Error),
+            2 => Some(Self::Warn),
+            3 => Some(Self::Info),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+/// The most verbose level that is printed, or 0 if logging is disabled.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Info as u8);
+
+/// Sets the most verbose level of message that is printed.
+///
+/// Passing `None` disables logging entirely. All levels are printed by default.
+pub fn set_max_level(level: Option<Level>) {
+    MAX_LEVEL.store(level.map_or(0, |level| level as u8), Ordering::Relaxed);
+}
+
+/// Returns the most verbose level of message that is printed, or `None` if logging is disabled.
+pub fn max_level() -> Option<Level> {
+    Level::from_u8(MAX_LEVEL.load(Ordering::Relaxed))
+}
+
+/// Returns `true` if messages with the given level are printed.
+pub fn enabled(level: Level) -> bool {
+    level as u8 <= MAX_LEVEL.load(Ordering::Relaxed)
+}
+
+#[doc(hidden)]
+pub fn __log(level: Level, args: fmt::Arguments) {
+    if !enabled(level) {
+        return;
+    }
+
+    let millis = crate::time::clock().millis();
+    let task = crate::task::current().name();
+    let task = task.as_deref().unwrap_or("<unknown>");
+
+    // Warnings and errors go to stderr so that they stand out in the terminal.
+    let mut stm = __SerialWriter::new(level <= Level::Warn);
+    _ = stm.write_fmt(format_args!("[{millis:>8}ms {level:<5} {task}] {args}\n"));
+}
+
+/// Logs a message at the [`Info`](crate::io::log::Level::Info) level.
+///
+/// Takes the same arguments as [`println!`](crate::println).
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)*) => {
+        $crate::io::log::__log($crate::io::log::Level::Info, core::format_args!($($arg)*))
+    };
+}
+
+/// Logs a message at the [`Warn`](crate::io::log::Level::Warn) level.
+///
+/// Takes the same arguments as [`println!`](crate::println).
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)*) => {
+        $crate::io::log::__log($crate::io::log::Level::Warn, core::format_args!($($arg)*))
+    };
+}
+
+/// Logs a message at the [`Error`](crate::io::log::Level::Error) level.
+///
+/// Takes the same arguments as [`println!`](crate::println).
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)*) => {
+        $crate::io::log::__log($crate::io::log::Level::Error, core::format_args!($($arg)*))
+    };
+}
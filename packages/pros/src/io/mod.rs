@@ -1,5 +1,8 @@
 //! Helpers for terminal I/O functionality.
 
+pub mod log;
 pub mod print_impl;
+mod stdio;
 
 pub use no_std_io::io::*;
+pub use stdio::{stdin, stdout, Stdin, Stdout};
@@ -0,0 +1,134 @@
+//! Standard input and output over the PROS terminal.
+//!
+//! [`stdout`] returns a line-buffered writer, and [`stdin`] returns a reader
+//! for text typed into the PROS terminal.
+//!
+//! ```ignore
+//! let mut line = String::new();
+//! loop {
+//!     line.clear();
+//!     stdin().read_line(&mut line)?;
+//!     writeln!(stdout(), "You typed: {}", line.trim_end())?;
+//! }
+//! ```
+
+use alloc::{string::String, vec::Vec};
+
+use no_std_io::io::{self, Read, Write};
+
+use crate::sync::Mutex;
+
+const STDIN_FILENO: i32 = 0;
+const STDOUT_FILENO: i32 = 1;
+
+lazy_static::lazy_static! {
+    static ref STDOUT_BUFFER: Mutex<Vec<u8>> = Mutex::new(Vec::new());
+}
+
+/// A handle to the standard output of the program.
+///
+/// Output is buffered until a newline is written or the handle is flushed.
+/// Text printed with [`println!`](crate::println) is not buffered, so it may appear
+/// before text written to [`Stdout`] that has not been flushed yet.
+///
+/// Created by [`stdout`].
+#[derive(Debug, Clone, Copy)]
+pub struct Stdout {
+    _private: (),
+}
+
+/// Returns a handle to the standard output of the program.
+pub fn stdout() -> Stdout {
+    Stdout { _private: () }
+}
+
+fn write_all_raw(fd: i32, mut bytes: &[u8]) -> io::Result<()> {
+    while !bytes.is_empty() {
+        let written = unsafe { pros_sys::write(fd, bytes.as_ptr().cast(), bytes.len()) };
+        if written <= 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                "failed to write to the terminal",
+            ));
+        }
+        bytes = &bytes[written as usize..];
+    }
+    Ok(())
+}
+
+impl Write for Stdout {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut buffer = STDOUT_BUFFER.lock();
+        buffer.extend_from_slice(buf);
+
+        if let Some(last_newline) = buffer.iter().rposition(|&byte| byte == b'\n') {
+            write_all_raw(STDOUT_FILENO, &buffer[..=last_newline])?;
+            buffer.drain(..=last_newline);
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let mut buffer = STDOUT_BUFFER.lock();
+        write_all_raw(STDOUT_FILENO, &buffer)?;
+        buffer.clear();
+        Ok(())
+    }
+}
+
+/// A handle to the standard input of the program, which receives text typed into the PROS terminal.
+///
+/// Created by [`stdin`].
+#[derive(Debug, Clone, Copy)]
+pub struct Stdin {
+    _private: (),
+}
+
+/// Returns a handle to the standard input of the program.
+pub fn stdin() -> Stdin {
+    Stdin { _private: () }
+}
+
+impl Stdin {
+    /// Reads a line of input into `buf`, including the trailing newline,
+    /// returning the number of bytes read.
+    ///
+    /// This blocks the current task until a full line has been read.
+    /// The line is appended to `buf` rather than replacing its contents.
+    pub fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
+        let mut line = Vec::new();
+        let mut byte = [0];
+        loop {
+            if self.read(&mut byte)? == 0 {
+                break;
+            }
+            line.push(byte[0]);
+            if byte[0] == b'\n' {
+                break;
+            }
+        }
+
+        let line = String::from_utf8(line).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "stdin did not contain valid UTF-8",
+            )
+        })?;
+        buf.push_str(&line);
+        Ok(line.len())
+    }
+}
+
+impl Read for Stdin {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = unsafe { pros_sys::read(STDIN_FILENO, buf.as_mut_ptr().cast(), buf.len()) };
+        if read < 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                "failed to read from the terminal",
+            ));
+        }
+        Ok(read as usize)
+    }
+}
@@ -2,14 +2,22 @@
 //!
 //! Use of button callbacks isn't recommended,
 //! instead run code when a button is pressed by checking the state of the buttons in a loop.
-//! Buttons can be checked with [`read_buttons`].
+//! Buttons can be checked with [`read_buttons`] or [`Button::is_pressed`],
+//! and presses can be awaited with [`wait_for_press`].
 
 extern crate alloc;
 
 use alloc::boxed::Box;
+use core::{
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicBool, Ordering},
+    task::{Context, Poll},
+};
 
 use crate::sync::Mutex;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ButtonsState {
     pub left_pressed: bool,
     pub middle_pressed: bool,
@@ -19,18 +27,82 @@ pub struct ButtonsState {
 pub fn read_buttons() -> ButtonsState {
     let bit_mask = unsafe { pros_sys::lcd_read_buttons() };
     ButtonsState {
-        left_pressed: bit_mask & 0b001 == bit_mask,
-        middle_pressed: bit_mask & 0b010 == bit_mask,
-        right_pressed: bit_mask & 0b100 == bit_mask,
+        left_pressed: bit_mask as i32 & pros_sys::LCD_BTN_LEFT != 0,
+        middle_pressed: bit_mask as i32 & pros_sys::LCD_BTN_CENTER != 0,
+        right_pressed: bit_mask as i32 & pros_sys::LCD_BTN_RIGHT != 0,
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Button {
     Left,
     Middle,
     Right,
 }
 
+static LAST_PRESSED: [AtomicBool; 3] = [
+    AtomicBool::new(false),
+    AtomicBool::new(false),
+    AtomicBool::new(false),
+];
+
+impl Button {
+    /// Returns `true` if the button is currently pressed.
+    pub fn is_pressed(self) -> bool {
+        let state = read_buttons();
+        match self {
+            Self::Left => state.left_pressed,
+            Self::Middle => state.middle_pressed,
+            Self::Right => state.right_pressed,
+        }
+    }
+
+    /// Returns `true` if the button is pressed now but was not pressed
+    /// the last time this function was called for the same button.
+    ///
+    /// Since the previous state is shared, only one part of a program should use this for a given button.
+    pub fn was_pressed(self) -> bool {
+        let pressed = self.is_pressed();
+        let was_pressed = LAST_PRESSED[self as usize].swap(pressed, Ordering::Relaxed);
+        pressed && !was_pressed
+    }
+}
+
+/// Waits for the given button to be pressed.
+///
+/// If the button is already held down, it must be released and pressed again.
+pub fn wait_for_press(button: Button) -> ButtonPressFuture {
+    ButtonPressFuture {
+        button,
+        released: !button.is_pressed(),
+    }
+}
+
+/// A future that completes once a LLEMU button is pressed.
+///
+/// Created by [`wait_for_press`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[derive(Debug)]
+pub struct ButtonPressFuture {
+    button: Button,
+    released: bool,
+}
+
+impl Future for ButtonPressFuture {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let pressed = self.button.is_pressed();
+        if pressed && self.released {
+            return Poll::Ready(());
+        }
+        self.released = !pressed;
+
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
 pub struct ButtonCallbacks {
     pub left_cb: Option<Box<dyn Fn() + Send>>,
     pub middle_cb: Option<Box<dyn Fn() + Send>>,
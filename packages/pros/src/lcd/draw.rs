@@ -0,0 +1,85 @@
+//! Draw shapes on the brain's screen.
+//!
+//! Shapes are drawn directly to the screen with the color set by [`set_pen_color`],
+//! which is much faster than drawing them pixel by pixel.
+//! Coordinates are measured in pixels from the top left corner of the screen.
+
+use pros_sys::PROS_ERR;
+
+use super::LcdError;
+use crate::{error::bail_on, lvgl::colors::LcdColor};
+
+/// Converts a color to the `0x00RRGGBB` format used by the screen API.
+const fn raw_color(color: LcdColor) -> u32 {
+    (color.0.red as u32) << 16 | (color.0.green as u32) << 8 | color.0.blue as u32
+}
+
+/// Sets the color that shapes are drawn with.
+pub fn set_pen_color(color: LcdColor) -> Result<(), LcdError> {
+    bail_on!(PROS_ERR as u32, unsafe {
+        pros_sys::screen_set_pen(raw_color(color))
+    });
+    Ok(())
+}
+
+/// Sets the color that the screen is filled with by [`erase`].
+pub fn set_eraser_color(color: LcdColor) -> Result<(), LcdError> {
+    bail_on!(PROS_ERR as u32, unsafe {
+        pros_sys::screen_set_eraser(raw_color(color))
+    });
+    Ok(())
+}
+
+/// Fills the entire screen with the color set by [`set_eraser_color`].
+pub fn erase() -> Result<(), LcdError> {
+    bail_on!(PROS_ERR as u32, unsafe { pros_sys::screen_erase() });
+    Ok(())
+}
+
+/// Draws a single pixel.
+pub fn draw_pixel(x: i16, y: i16) -> Result<(), LcdError> {
+    bail_on!(PROS_ERR as u32, unsafe {
+        pros_sys::screen_draw_pixel(x, y)
+    });
+    Ok(())
+}
+
+/// Draws a line between two points.
+pub fn draw_line(start: (i16, i16), end: (i16, i16)) -> Result<(), LcdError> {
+    bail_on!(PROS_ERR as u32, unsafe {
+        pros_sys::screen_draw_line(start.0, start.1, end.0, end.1)
+    });
+    Ok(())
+}
+
+/// Draws the outline of a rectangle with the given opposite corners.
+pub fn draw_rect(corner: (i16, i16), opposite_corner: (i16, i16)) -> Result<(), LcdError> {
+    bail_on!(PROS_ERR as u32, unsafe {
+        pros_sys::screen_draw_rect(corner.0, corner.1, opposite_corner.0, opposite_corner.1)
+    });
+    Ok(())
+}
+
+/// Draws a filled rectangle with the given opposite corners.
+pub fn fill_rect(corner: (i16, i16), opposite_corner: (i16, i16)) -> Result<(), LcdError> {
+    bail_on!(PROS_ERR as u32, unsafe {
+        pros_sys::screen_fill_rect(corner.0, corner.1, opposite_corner.0, opposite_corner.1)
+    });
+    Ok(())
+}
+
+/// Draws the outline of a circle.
+pub fn draw_circle(center: (i16, i16), radius: i16) -> Result<(), LcdError> {
+    bail_on!(PROS_ERR as u32, unsafe {
+        pros_sys::screen_draw_circle(center.0, center.1, radius)
+    });
+    Ok(())
+}
+
+/// Draws a filled circle.
+pub fn fill_circle(center: (i16, i16), radius: i16) -> Result<(), LcdError> {
+    bail_on!(PROS_ERR as u32, unsafe {
+        pros_sys::screen_fill_circle(center.0, center.1, radius)
+    });
+    Ok(())
+}
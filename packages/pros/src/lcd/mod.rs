@@ -5,10 +5,13 @@
 
 use snafu::Snafu;
 
-use crate::{lvgl::colors::LcdColor, sync::Mutex};
+use crate::{error::map_errno, lvgl::colors::LcdColor, sync::Mutex};
 
 pub mod buttons;
+pub mod draw;
 pub mod macros;
+pub mod selector;
+pub mod touch;
 
 pub use macros::*;
 
@@ -26,7 +29,7 @@ pub fn set_background_color(color: LcdColor) {
 pub fn set_text_color(color: LcdColor) {
     unsafe {
         pros_sys::lcd_initialize();
-        pros_sys::lcd_set_background_color(*color);
+        pros_sys::lcd_set_text_color(*color);
     }
 }
 
@@ -40,4 +43,13 @@ lazy_static::lazy_static! {
 pub enum LcdError {
     #[snafu(display("LCD not initialized"))]
     NotInitialized,
+
+    #[snafu(display("Another resource is already using the screen"))]
+    ConcurrentAccess,
+}
+
+map_errno! {
+    LcdError {
+        EACCES => Self::ConcurrentAccess,
+    }
 }
@@ -0,0 +1,142 @@
+//! Autonomous routine selection using the LLEMU.
+//!
+//! [`AutonSelector`] displays a list of routines on the LLEMU.
+//! The left and right buttons cycle through the routines and the middle button confirms the selection.
+//!
+//! ```ignore
+//! let mut selector = AutonSelector::new(["Left side", "Right side", "Skills"]);
+//! let routine = selector.wait_for_selection().await;
+//! ```
+
+use alloc::{ffi::CString, format, string::String, vec::Vec};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use super::buttons::{read_buttons, ButtonsState};
+
+/// A menu for choosing an autonomous routine with the LLEMU buttons.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AutonSelector {
+    routines: Vec<String>,
+    selected: usize,
+}
+
+impl AutonSelector {
+    /// Creates a new selector with the given routine names.
+    ///
+    /// The first routine is selected by default.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if no routines are given.
+    pub fn new<I>(routines: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let routines: Vec<String> = routines.into_iter().map(Into::into).collect();
+        assert!(
+            !routines.is_empty(),
+            "An autonomous selector must have at least one routine"
+        );
+
+        Self {
+            routines,
+            selected: 0,
+        }
+    }
+
+    /// Returns the index of the currently selected routine.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Returns the name of the currently selected routine.
+    pub fn selected_name(&self) -> &str {
+        &self.routines[self.selected]
+    }
+
+    /// Displays the selector and waits for a routine to be confirmed with the middle button,
+    /// resolving to the index of the chosen routine.
+    pub fn wait_for_selection(&mut self) -> SelectionFuture<'_> {
+        self.render(false);
+        SelectionFuture {
+            last_buttons: read_buttons(),
+            selector: self,
+        }
+    }
+
+    fn cycle(&mut self, forward: bool) {
+        let len = self.routines.len();
+        self.selected = if forward {
+            (self.selected + 1) % len
+        } else {
+            (self.selected + len - 1) % len
+        };
+        self.render(false);
+    }
+
+    fn render(&self, confirmed: bool) {
+        let lines = if confirmed {
+            [
+                String::from("Selected autonomous:"),
+                String::from(self.selected_name()),
+                String::new(),
+            ]
+        } else {
+            [
+                String::from("Select autonomous:"),
+                format!("< {} >", self.selected_name()),
+                format!("{}/{}", self.selected + 1, self.routines.len()),
+            ]
+        };
+
+        unsafe {
+            pros_sys::lcd_initialize();
+            pros_sys::lcd_clear();
+        }
+        for (line, text) in lines.into_iter().enumerate() {
+            let text =
+                CString::new(text).expect("routine names should not contain null (U+0000) bytes");
+            unsafe {
+                pros_sys::lcd_set_text(line as i16 * 2, text.as_ptr());
+            }
+        }
+    }
+}
+
+/// A future that completes once a routine has been confirmed on an [`AutonSelector`].
+///
+/// Created by [`AutonSelector::wait_for_selection`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[derive(Debug)]
+pub struct SelectionFuture<'a> {
+    selector: &'a mut AutonSelector,
+    last_buttons: ButtonsState,
+}
+
+impl<'a> Future for SelectionFuture<'a> {
+    type Output = usize;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let buttons = read_buttons();
+        let last_buttons = core::mem::replace(&mut self.last_buttons, buttons);
+
+        if buttons.middle_pressed && !last_buttons.middle_pressed {
+            self.selector.render(true);
+            return Poll::Ready(self.selector.selected);
+        }
+        if buttons.left_pressed && !last_buttons.left_pressed {
+            self.selector.cycle(false);
+        }
+        if buttons.right_pressed && !last_buttons.right_pressed {
+            self.selector.cycle(true);
+        }
+
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
@@ -0,0 +1,121 @@
+//! Read touches on the brain's screen.
+//!
+//! Unlike the LLEMU buttons, touches are reported anywhere on the screen,
+//! which allows for building custom touch interfaces.
+//! Touches can be checked in a loop with [`touch_status`], or handled with callbacks registered by [`on_touch`].
+
+use alloc::boxed::Box;
+
+use pros_sys::PROS_ERR;
+
+use super::LcdError;
+use crate::{error::bail_on, sync::Mutex};
+
+/// The state of the last touch on the screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TouchState {
+    /// The screen is not being touched.
+    Released,
+    /// The screen was just touched.
+    Pressed,
+    /// The screen has been touched for a while.
+    Held,
+}
+
+impl TouchState {
+    const fn raw(self) -> pros_sys::last_touch_e_t {
+        match self {
+            Self::Released => pros_sys::E_TOUCH_RELEASED,
+            Self::Pressed => pros_sys::E_TOUCH_PRESSED,
+            Self::Held => pros_sys::E_TOUCH_HELD,
+        }
+    }
+}
+
+/// Information about the last touch on the screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TouchStatus {
+    /// The x coordinate of the touch in pixels, from the left edge of the screen.
+    pub x: i16,
+    /// The y coordinate of the touch in pixels, from the top edge of the screen.
+    pub y: i16,
+    /// Whether the screen is currently being touched.
+    pub state: TouchState,
+    /// The number of times the screen has been pressed.
+    pub press_count: i32,
+    /// The number of times the screen has been released.
+    pub release_count: i32,
+}
+
+/// Gets information about the last touch on the screen.
+pub fn touch_status() -> Result<TouchStatus, LcdError> {
+    let status = unsafe { pros_sys::screen_touch_status() };
+    let state = match bail_on!(pros_sys::E_TOUCH_ERROR, status.touch_status) {
+        pros_sys::E_TOUCH_PRESSED => TouchState::Pressed,
+        pros_sys::E_TOUCH_HELD => TouchState::Held,
+        _ => TouchState::Released,
+    };
+
+    Ok(TouchStatus {
+        x: status.x,
+        y: status.y,
+        state,
+        press_count: status.press_count,
+        release_count: status.release_count,
+    })
+}
+
+struct TouchCallbacks {
+    released: Option<Box<dyn Fn() + Send>>,
+    pressed: Option<Box<dyn Fn() + Send>>,
+    held: Option<Box<dyn Fn() + Send>>,
+}
+
+lazy_static::lazy_static! {
+    static ref TOUCH_CALLBACKS: Mutex<TouchCallbacks> = Mutex::new(TouchCallbacks {
+        released: None,
+        pressed: None,
+        held: None,
+    });
+}
+
+/// Registers a callback that runs whenever the screen enters the given touch state.
+///
+/// Registering another callback for the same state replaces the previous one.
+/// Callbacks run in a separate task, so they should not block for long.
+pub fn on_touch(callback: impl Fn() + Send + 'static, state: TouchState) -> Result<(), LcdError> {
+    extern "C" fn released_cb() {
+        if let Some(cb) = &TOUCH_CALLBACKS.lock().released {
+            cb();
+        }
+    }
+
+    extern "C" fn pressed_cb() {
+        if let Some(cb) = &TOUCH_CALLBACKS.lock().pressed {
+            cb();
+        }
+    }
+
+    extern "C" fn held_cb() {
+        if let Some(cb) = &TOUCH_CALLBACKS.lock().held {
+            cb();
+        }
+    }
+
+    let mut callbacks = TOUCH_CALLBACKS.lock();
+    let (slot, trampoline): (_, extern "C" fn()) = match state {
+        TouchState::Released => (&mut callbacks.released, released_cb),
+        TouchState::Pressed => (&mut callbacks.pressed, pressed_cb),
+        TouchState::Held => (&mut callbacks.held, held_cb),
+    };
+
+    // PROS keeps every registered callback, so the trampoline is only registered once per state.
+    if slot.is_none() {
+        bail_on!(PROS_ERR as u32, unsafe {
+            pros_sys::screen_touch_callback(Some(trampoline), state.raw())
+        });
+    }
+    *slot = Some(Box::new(callback));
+
+    Ok(())
+}
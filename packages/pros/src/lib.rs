@@ -52,7 +52,7 @@
 //! You may have noticed the `#[derive(Default)]` attribute on these Robot structs.
 //! If you want to learn why, look at the docs for [`async_robot`] or [`sync_robot`].
 
-#![feature(error_in_core, stdsimd, negative_impls)]
+#![feature(error_in_core, stdsimd, negative_impls, alloc_error_handler)]
 #![no_std]
 
 extern crate alloc;
@@ -63,6 +63,8 @@ pub mod async_runtime;
 pub mod devices;
 pub mod error;
 pub mod pid;
+pub mod profile;
+pub mod rand;
 pub mod sync;
 #[macro_use]
 pub mod task;
@@ -78,6 +80,7 @@ pub mod lcd;
 pub mod competition;
 pub mod io;
 pub mod lvgl;
+pub mod mem;
 pub mod time;
 pub mod usd;
 
@@ -355,6 +358,35 @@ pub fn panic(info: &core::panic::PanicInfo) -> ! {
     // panic message here
     println!("task '{task_name}' {info}");
 
+    // Tasks spawned with `Builder::spawn_with_result` report their panic to their join handle instead of ending the program.
+    // The task is deleted without unwinding, so any locks it holds stay locked forever.
+    if let Some(slot) = task::PANIC_SLOT.take() {
+        *slot.lock() = Some(alloc::format!("task '{task_name}' {info}"));
+        unsafe {
+            pros_sys::task_delete(core::ptr::null());
+        }
+    }
+
+    unsafe {
+        #[cfg(target_arch = "wasm32")]
+        wasm_env::sim_log_backtrace();
+        pros_sys::exit(1);
+    }
+}
+
+#[alloc_error_handler]
+fn alloc_error(layout: core::alloc::Layout) -> ! {
+    // Allocating here would fail again, so the task name is borrowed rather than copied into a `String`.
+    let task_name =
+        unsafe { core::ffi::CStr::from_ptr(pros_sys::task_get_name(core::ptr::null())) };
+    let task_name = task_name.to_str().unwrap_or("<unknown>");
+
+    println!(
+        "task '{task_name}' ran out of memory while allocating {} bytes (align {})",
+        layout.size(),
+        layout.align()
+    );
+
     unsafe {
         #[cfg(target_arch = "wasm32")]
         wasm_env::sim_log_backtrace();
@@ -378,11 +410,16 @@ pub mod prelude {
                 digital::{AdiDigitalIn, AdiDigitalOut},
                 encoder::AdiEncoder,
                 gyro::AdiGyro,
+                led::AdiLed,
+                line_tracker::AdiLineTracker,
                 motor::AdiMotor,
+                pneumatic::{DoublePneumatic, Pneumatic},
                 potentiometer::{AdiPotentiometer, AdiPotentiometerType},
+                servo::AdiServo,
                 ultrasonic::AdiUltrasonic,
                 AdiDevice, AdiPort,
             },
+            odometry::{Odometry, Pose, TrackingWheel},
             peripherals::{DynamicPeripherals, Peripherals},
             position::Position,
             smart::{
@@ -390,12 +427,14 @@ pub mod prelude {
                 gps::GpsSensor,
                 imu::InertialSensor,
                 link::{Link, RxLink, TxLink},
-                motor::{BrakeMode, Gearset, Motor},
+                motor::{BrakeMode, Gearset, Motor, MotorGroup, MotorUnits},
                 optical::OpticalSensor,
                 rotation::RotationSensor,
+                serial::SerialPort,
                 vision::VisionSensor,
                 SmartDevice, SmartPort,
             },
+            velocity::Velocity,
         },
         eprint, eprintln,
         error::PortError,
@@ -403,7 +442,9 @@ pub mod prelude {
         lcd::{buttons::Button, llemu_print, llemu_println, LcdError},
         os_task_local,
         pid::*,
-        print, println, sync_robot,
+        print, println,
+        profile::{ProfileState, TrapezoidalProfile},
+        sync_robot,
         task::{delay, sleep, spawn},
         AsyncRobot, SyncRobot,
     };
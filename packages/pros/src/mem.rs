@@ -0,0 +1,153 @@
+//! Heap memory statistics.
+//!
+//! [`heap_stats`] reports how much of the brain's heap is in use, which is useful
+//! for finding memory leaks and checking how close a program is to running out of memory.
+//!
+//! ```ignore
+//! let stats = mem::heap_stats();
+//! println!("{} bytes used, {} bytes free", stats.used, stats.free);
+//! ```
+//!
+//! With the `tracking-alloc` feature enabled, every allocation made through the global allocator
+//! is counted. This makes it possible to check that a piece of code does not leak memory:
+//!
+//! ```ignore
+//! let before = mem::allocation_count();
+//! control_loop_iteration()?;
+//! assert_eq!(mem::allocation_count(), before);
+//! ```
+
+#[cfg(feature = "tracking-alloc")]
+use core::{
+    alloc::{GlobalAlloc, Layout},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// A snapshot of heap usage, returned by [`heap_stats`].
+///
+/// All sizes are in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HeapStats {
+    /// The number of bytes in use by allocations, including allocator bookkeeping.
+    pub used: usize,
+    /// The number of bytes that are not in use.
+    ///
+    /// Free memory may be split between several blocks, so an allocation of this size can still fail.
+    pub free: usize,
+    /// The size of the free block at the end of the heap,
+    /// which is the largest allocation that is guaranteed to succeed.
+    ///
+    /// Freed blocks elsewhere in the heap are not counted, so larger allocations may still succeed.
+    pub largest_free_block: usize,
+}
+
+/// Returns statistics about the heap.
+///
+/// This locks the allocator, so it should not be called in a tight loop.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn heap_stats() -> HeapStats {
+    let (info, heap_start, heap_end, program_break) = unsafe {
+        (
+            pros_sys::mallinfo(),
+            core::ptr::addr_of!(pros_sys::_heap_start) as usize,
+            core::ptr::addr_of!(pros_sys::_heap_end) as usize,
+            pros_sys::sbrk(0) as usize,
+        )
+    };
+
+    // Memory past the program break has not been claimed by the allocator yet,
+    // but is contiguous with the free chunk at the top of the heap.
+    let unclaimed = heap_end.saturating_sub(program_break);
+    let total = heap_end - heap_start;
+    let used = info.uordblks;
+
+    HeapStats {
+        used,
+        free: total.saturating_sub(used),
+        largest_free_block: info.keepcost + unclaimed,
+    }
+}
+
+#[cfg(feature = "tracking-alloc")]
+static ALLOCATION_COUNT: AtomicUsize = AtomicUsize::new(0);
+#[cfg(feature = "tracking-alloc")]
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+#[cfg(feature = "tracking-alloc")]
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// Returns the number of allocations that have not been freed yet.
+#[cfg(feature = "tracking-alloc")]
+pub fn allocation_count() -> usize {
+    ALLOCATION_COUNT.load(Ordering::Relaxed)
+}
+
+/// Returns the total size in bytes of the allocations that have not been freed yet.
+///
+/// Unlike [`HeapStats::used`], this does not include allocator bookkeeping.
+#[cfg(feature = "tracking-alloc")]
+pub fn allocated_bytes() -> usize {
+    ALLOCATED_BYTES.load(Ordering::Relaxed)
+}
+
+/// Returns the highest value of [`allocated_bytes`] since the program started
+/// or [`reset_peak`] was last called.
+#[cfg(feature = "tracking-alloc")]
+pub fn peak_usage() -> usize {
+    PEAK_BYTES.load(Ordering::Relaxed)
+}
+
+/// Resets [`peak_usage`] to the current value of [`allocated_bytes`].
+#[cfg(feature = "tracking-alloc")]
+pub fn reset_peak() {
+    PEAK_BYTES.store(ALLOCATED_BYTES.load(Ordering::Relaxed), Ordering::Relaxed);
+}
+
+#[cfg(feature = "tracking-alloc")]
+fn record_alloc(size: usize) {
+    ALLOCATION_COUNT.fetch_add(1, Ordering::Relaxed);
+    let allocated = ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK_BYTES.fetch_max(allocated, Ordering::Relaxed);
+}
+
+#[cfg(feature = "tracking-alloc")]
+fn record_dealloc(size: usize) {
+    ALLOCATION_COUNT.fetch_sub(1, Ordering::Relaxed);
+    ALLOCATED_BYTES.fetch_sub(size, Ordering::Relaxed);
+}
+
+/// Wraps the global allocator to count allocations.
+#[cfg(feature = "tracking-alloc")]
+pub(crate) struct TrackingAllocator<A>(pub A);
+
+#[cfg(feature = "tracking-alloc")]
+unsafe impl<A: GlobalAlloc> GlobalAlloc for TrackingAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { self.0.alloc(layout) };
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { self.0.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { self.0.dealloc(ptr, layout) };
+        record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { self.0.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
@@ -1,7 +1,21 @@
 //! PID controllers.
 //!
-//! PID controllers are first created with [`PidController::new`]
+//! PID controllers are first created with [`PidController::new`] or [`PidController::builder`]
 //! and then can be utilized by calling [`PidController::update`] repeatedly.
+//!
+//! Code that should work with any control algorithm can be written against the
+//! [`FeedbackController`] trait instead.
+
+use core::time::Duration;
+
+/// A control algorithm that computes an output from the error between a setpoint and a measurement.
+pub trait FeedbackController {
+    /// Computes the next output of the controller, given the time elapsed since the last update.
+    fn update(&mut self, setpoint: f64, measurement: f64, dt: Duration) -> f64;
+
+    /// Resets any state accumulated by the controller.
+    fn reset(&mut self);
+}
 
 /// A proportional–integral–derivative controller.
 ///
@@ -13,53 +27,244 @@
 pub struct PidController {
     /// Proportional constant. This is multiplied by the error to get the
     /// proportional component of the output.
-    pub kp: f32,
+    pub kp: f64,
     /// Integral constant. This accounts for the past values of the error.
-    pub ki: f32,
+    pub ki: f64,
     /// Derivative constant. This allows you to change the motor behavior
     /// based on the rate of change of the error (predicting future values).
-    pub kd: f32,
+    pub kd: f64,
+    /// Feedforward constant. This is multiplied by the setpoint and added to the output,
+    /// which is useful for velocity control where some output is always needed to hold the setpoint.
+    pub kf: f64,
+
+    integral_limit: Option<f64>,
+    integral_zone: Option<f64>,
+    derivative_source: DerivativeSource,
 
-    last_time: i32,
-    last_position: f32,
-    i: f32,
+    last_time: u32,
+    /// The error and position from the previous update, or `None` if there hasn't been one since the last reset.
+    last_sample: Option<(f64, f64)>,
+    i: f64,
+    last_components: PidComponents,
 }
 
 impl PidController {
-    pub fn new(kp: f32, ki: f32, kd: f32) -> Self {
+    /// Creates a new PID controller with the given constants and no feedforward.
+    pub fn new(kp: f64, ki: f64, kd: f64) -> Self {
         Self {
             kp,
             ki,
             kd,
+            kf: 0.0,
+            integral_limit: None,
+            integral_zone: None,
+            derivative_source: DerivativeSource::Error,
             last_time: 0,
-            last_position: 0.0,
+            last_sample: None,
             i: 0.0,
+            last_components: PidComponents::default(),
         }
     }
 
-    pub fn update(&mut self, setpoint: f32, position: f32) -> f32 {
-        let time = unsafe { pros_sys::clock() };
-        let mut delta_time = (time - self.last_time) as f32 / pros_sys::CLOCKS_PER_SEC as f32;
+    /// Creates a builder for configuring a PID controller.
+    ///
+    /// ```rust
+    /// # use pros::pid::PidController;
+    /// let pid = PidController::builder().kp(0.5).kd(0.1).kf(0.02).build();
+    /// ```
+    pub fn builder() -> PidControllerBuilder {
+        PidControllerBuilder::default()
+    }
+
+    /// Computes the next output of the controller given a setpoint and the current position.
+    ///
+    /// The time elapsed since the last update is measured automatically.
+    pub fn update(&mut self, setpoint: f64, position: f64) -> f64 {
+        let time = crate::time::clock().millis();
+        let delta_time = time.wrapping_sub(self.last_time) as f64 / 1000.0;
+        self.last_time = time;
+
+        self.step(setpoint, position, delta_time)
+    }
+
+    fn step(&mut self, setpoint: f64, position: f64, mut delta_time: f64) -> f64 {
         if delta_time == 0.0 {
             delta_time += 0.001;
         }
         let error = setpoint - position;
 
-        self.i += error * delta_time;
-
-        let p = self.kp * error;
-        let i = self.ki * self.i;
+        if self.integral_zone.map_or(true, |zone| error.abs() < zone) {
+            self.i += error * delta_time;
+        }
+        if let Some(limit) = self.integral_limit {
+            self.i = self.i.clamp(-limit, limit);
+        }
 
-        let mut d = (position - self.last_position) / delta_time;
+        // There is nothing to differentiate against on the first step, so no derivative is applied.
+        let mut d = match (self.last_sample, self.derivative_source) {
+            (None, _) => 0.0,
+            (Some((last_error, _)), DerivativeSource::Error) => (error - last_error) / delta_time,
+            // The error's derivative is the negative of the position's derivative when the setpoint is constant.
+            (Some((_, last_position)), DerivativeSource::Measurement) => {
+                -(position - last_position) / delta_time
+            }
+        };
         if d.is_nan() {
             d = 0.0
         }
 
-        let output = p + i + d;
+        self.last_components = PidComponents {
+            proportional: self.kp * error,
+            integral: self.ki * self.i,
+            derivative: self.kd * d,
+            feedforward: self.kf * setpoint,
+        };
 
-        self.last_position = position;
-        self.last_time = time;
+        self.last_sample = Some((error, position));
+
+        self.last_components.sum()
+    }
+
+    /// Limits the accumulated integral to the range `[-limit, limit]` to prevent integral windup.
+    ///
+    /// A limit of 0 disables integral accumulation entirely.
+    pub fn set_integral_limit(&mut self, limit: f64) {
+        self.integral_limit = Some(limit.abs());
+    }
+
+    /// Only accumulates the integral while the absolute error is below `threshold`.
+    ///
+    /// This prevents the integral from building up during large movements,
+    /// where the proportional term alone is enough to drive the system.
+    pub fn set_integral_zone(&mut self, threshold: f64) {
+        self.integral_zone = Some(threshold.abs());
+    }
+
+    /// Sets what the derivative term is calculated from.
+    ///
+    /// Defaults to [`DerivativeSource::Error`].
+    pub fn set_derivative_source(&mut self, derivative_source: DerivativeSource) {
+        self.derivative_source = derivative_source;
+    }
+
+    /// Returns the individual contributions of each term to the last output of [`Self::update`].
+    ///
+    /// This is useful for tuning and logging.
+    pub fn last_components(&self) -> PidComponents {
+        self.last_components
+    }
+}
+
+impl FeedbackController for PidController {
+    fn update(&mut self, setpoint: f64, measurement: f64, dt: Duration) -> f64 {
+        self.step(setpoint, measurement, dt.as_secs_f64())
+    }
+
+    fn reset(&mut self) {
+        self.last_time = crate::time::clock().millis();
+        self.last_sample = None;
+        self.i = 0.0;
+        self.last_components = PidComponents::default();
+    }
+}
+
+/// What the derivative term of a [`PidController`] is calculated from.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DerivativeSource {
+    /// The derivative is taken on the error.
+    ///
+    /// This causes a large spike in output ("derivative kick") whenever the setpoint changes.
+    #[default]
+    Error,
+    /// The derivative is taken on the measured position.
+    ///
+    /// This avoids derivative kick, since the output no longer depends on how quickly the setpoint changes.
+    Measurement,
+}
+
+/// The individual contributions of each term of a [`PidController`] to its output.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct PidComponents {
+    /// The proportional term of the output.
+    pub proportional: f64,
+    /// The integral term of the output.
+    pub integral: f64,
+    /// The derivative term of the output.
+    pub derivative: f64,
+    /// The feedforward term of the output.
+    pub feedforward: f64,
+}
+
+impl PidComponents {
+    /// Returns the total output of the controller.
+    pub fn sum(&self) -> f64 {
+        self.proportional + self.integral + self.derivative + self.feedforward
+    }
+}
+
+/// A builder for [`PidController`]s. Constants that are not set default to zero.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct PidControllerBuilder {
+    kp: f64,
+    ki: f64,
+    kd: f64,
+    kf: f64,
+    integral_limit: Option<f64>,
+    integral_zone: Option<f64>,
+    derivative_source: DerivativeSource,
+}
+
+impl PidControllerBuilder {
+    /// Sets the proportional constant.
+    pub fn kp(mut self, kp: f64) -> Self {
+        self.kp = kp;
+        self
+    }
+
+    /// Sets the integral constant.
+    pub fn ki(mut self, ki: f64) -> Self {
+        self.ki = ki;
+        self
+    }
+
+    /// Sets the derivative constant.
+    pub fn kd(mut self, kd: f64) -> Self {
+        self.kd = kd;
+        self
+    }
+
+    /// Sets the feedforward constant.
+    pub fn kf(mut self, kf: f64) -> Self {
+        self.kf = kf;
+        self
+    }
 
-        output
+    /// Sets the integral limit. See [`PidController::set_integral_limit`].
+    pub fn integral_limit(mut self, limit: f64) -> Self {
+        self.integral_limit = Some(limit.abs());
+        self
+    }
+
+    /// Sets the integral zone. See [`PidController::set_integral_zone`].
+    pub fn integral_zone(mut self, threshold: f64) -> Self {
+        self.integral_zone = Some(threshold.abs());
+        self
+    }
+
+    /// Sets what the derivative term is calculated from. See [`PidController::set_derivative_source`].
+    pub fn derivative_source(mut self, derivative_source: DerivativeSource) -> Self {
+        self.derivative_source = derivative_source;
+        self
+    }
+
+    /// Builds the PID controller.
+    pub fn build(self) -> PidController {
+        PidController {
+            kf: self.kf,
+            integral_limit: self.integral_limit,
+            integral_zone: self.integral_zone,
+            derivative_source: self.derivative_source,
+            ..PidController::new(self.kp, self.ki, self.kd)
+        }
     }
 }
@@ -0,0 +1,129 @@
+//! Motion profiles.
+//!
+//! Motion profiles plan out how a mechanism should move over time so that it accelerates and decelerates smoothly.
+//! A profile is sampled at the current time to get a setpoint, which can then be fed into a
+//! [`PidController`](crate::pid::PidController) or another [`FeedbackController`](crate::pid::FeedbackController).
+
+use core::time::Duration;
+
+/// The planned state of a mechanism at some point in a motion profile.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct ProfileState {
+    /// Distance travelled since the start of the profile.
+    pub position: f64,
+    /// Velocity in distance units per second.
+    pub velocity: f64,
+    /// Acceleration in distance units per second squared.
+    pub acceleration: f64,
+}
+
+/// A trapezoidal velocity profile.
+///
+/// The profile accelerates at a constant rate up to the maximum velocity, cruises,
+/// and then decelerates at the same rate to stop exactly at the target distance.
+/// If the distance is too short to reach the maximum velocity, the cruise phase is skipped.
+///
+/// Units are up to the user, as long as they are consistent:
+/// a distance in inches requires a velocity in inches per second and an acceleration in inches per second squared.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrapezoidalProfile {
+    distance: f64,
+    peak_velocity: f64,
+    acceleration: f64,
+    accel_time: f64,
+    cruise_time: f64,
+}
+
+impl TrapezoidalProfile {
+    /// Creates a new profile that moves `distance` units, starting and ending at rest.
+    ///
+    /// Negative distances create a profile that moves in the negative direction.
+    /// The signs of `max_velocity` and `max_acceleration` are ignored.
+    pub fn new(max_velocity: f64, max_acceleration: f64, distance: f64) -> Self {
+        let max_velocity = max_velocity.abs();
+        let acceleration = max_acceleration.abs();
+        let abs_distance = distance.abs();
+
+        if max_velocity == 0.0 || acceleration == 0.0 || abs_distance == 0.0 {
+            return Self {
+                distance,
+                peak_velocity: 0.0,
+                acceleration,
+                accel_time: 0.0,
+                cruise_time: 0.0,
+            };
+        }
+
+        let mut accel_time = max_velocity / acceleration;
+        let mut peak_velocity = max_velocity;
+        let accel_distance = 0.5 * acceleration * accel_time * accel_time;
+
+        let cruise_time = if 2.0 * accel_distance > abs_distance {
+            // The maximum velocity can't be reached, so the profile is triangular.
+            accel_time = libm::sqrt(abs_distance / acceleration);
+            peak_velocity = acceleration * accel_time;
+            0.0
+        } else {
+            (abs_distance - 2.0 * accel_distance) / max_velocity
+        };
+
+        Self {
+            distance,
+            peak_velocity,
+            acceleration,
+            accel_time,
+            cruise_time,
+        }
+    }
+
+    /// Returns the time it takes to complete the profile.
+    pub fn total_duration(&self) -> Duration {
+        Duration::from_secs_f64(2.0 * self.accel_time + self.cruise_time)
+    }
+
+    /// Returns the planned state of the mechanism `t` after the start of the profile.
+    ///
+    /// Times after the end of the profile return the final state, at rest at the target distance.
+    pub fn sample(&self, t: Duration) -> ProfileState {
+        let t = t.as_secs_f64();
+        let decel_start = self.accel_time + self.cruise_time;
+        let accel_distance = 0.5 * self.acceleration * self.accel_time * self.accel_time;
+
+        let state = if t < self.accel_time {
+            ProfileState {
+                position: 0.5 * self.acceleration * t * t,
+                velocity: self.acceleration * t,
+                acceleration: self.acceleration,
+            }
+        } else if t < decel_start {
+            ProfileState {
+                position: accel_distance + self.peak_velocity * (t - self.accel_time),
+                velocity: self.peak_velocity,
+                acceleration: 0.0,
+            }
+        } else if t < decel_start + self.accel_time {
+            let remaining = decel_start + self.accel_time - t;
+            ProfileState {
+                position: self.distance.abs() - 0.5 * self.acceleration * remaining * remaining,
+                velocity: self.acceleration * remaining,
+                acceleration: -self.acceleration,
+            }
+        } else {
+            ProfileState {
+                position: self.distance.abs(),
+                velocity: 0.0,
+                acceleration: 0.0,
+            }
+        };
+
+        if self.distance.is_sign_negative() {
+            ProfileState {
+                position: -state.position,
+                velocity: -state.velocity,
+                acceleration: -state.acceleration,
+            }
+        } else {
+            state
+        }
+    }
+}
@@ -0,0 +1,194 @@
+//! Pseudorandom number generation.
+//!
+//! [`random`] and [`random_range`] draw from a global generator that is seeded from the time
+//! since the program started and noise in the battery readings. Call [`seed`] with a fixed
+//! value to make the numbers the same on every run, which is useful for reproducing a problem
+//! in an autonomous routine.
+//!
+//! ```ignore
+//! rand::seed(42);
+//! let coin_flip: bool = rand::random();
+//! let delay = rand::random_range(100..500);
+//! ```
+//!
+//! The generator is a [PCG](https://www.pcg-random.org/) and is not suitable for cryptography.
+
+use core::ops::Range;
+
+use crate::sync::Mutex;
+
+const MULTIPLIER: u64 = 6364136223846793005;
+const INCREMENT: u64 = 1442695040888963407;
+
+lazy_static::lazy_static! {
+    static ref RNG: Mutex<Rng> = Mutex::new(Rng::from_entropy());
+}
+
+/// A small, fast pseudorandom number generator.
+///
+/// Generators created with the same seed produce the same sequence of numbers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Creates a new generator from a seed.
+    pub const fn new(seed: u64) -> Self {
+        let mut rng = Self { state: 0 };
+        rng.step();
+        rng.state = rng.state.wrapping_add(seed);
+        rng.step();
+        rng
+    }
+
+    /// Creates a new generator seeded from the time since the program started and noise in the battery readings.
+    pub fn from_entropy() -> Self {
+        let (micros, voltage, current) = unsafe {
+            (
+                pros_sys::rtos::micros(),
+                pros_sys::battery_get_voltage(),
+                pros_sys::battery_get_current(),
+            )
+        };
+        Self::new(micros ^ ((voltage as u64) << 32) ^ ((current as u64) << 16))
+    }
+
+    const fn step(&mut self) {
+        self.state = self.state.wrapping_mul(MULTIPLIER).wrapping_add(INCREMENT);
+    }
+
+    /// Returns a random `u32`.
+    pub fn next_u32(&mut self) -> u32 {
+        let old = self.state;
+        self.step();
+        let xorshifted = (((old >> 18) ^ old) >> 27) as u32;
+        xorshifted.rotate_right((old >> 59) as u32)
+    }
+
+    /// Returns a random `u64`.
+    pub fn next_u64(&mut self) -> u64 {
+        (self.next_u32() as u64) << 32 | self.next_u32() as u64
+    }
+
+    /// Returns a random value of type `T`.
+    pub fn random<T: Random>(&mut self) -> T {
+        T::random(self)
+    }
+
+    /// Returns a random value in the given range.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the range is empty.
+    pub fn random_range<T: RandomRange>(&mut self, range: Range<T>) -> T {
+        T::random_range(self, range)
+    }
+
+    /// Returns a random number less than `bound` without favoring any number.
+    fn below(&mut self, bound: u64) -> u64 {
+        // Rejecting the lowest `2^64 % bound` values leaves a multiple of `bound` possible values.
+        let threshold = bound.wrapping_neg() % bound;
+        loop {
+            let value = self.next_u64();
+            if value >= threshold {
+                return value % bound;
+            }
+        }
+    }
+}
+
+/// A type that can be generated randomly with [`random`].
+pub trait Random {
+    /// Generates a random value.
+    ///
+    /// Integers are uniformly distributed over all of their values,
+    /// and floating point numbers are uniformly distributed between 0 and 1.
+    fn random(rng: &mut Rng) -> Self;
+}
+
+/// A type that can be generated randomly within a range with [`random_range`].
+pub trait RandomRange: Sized {
+    /// Generates a random value in the given range.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the range is empty.
+    fn random_range(rng: &mut Rng, range: Range<Self>) -> Self;
+}
+
+macro_rules! impl_random_int {
+    ($($ty:ty),*) => {
+        $(
+            impl Random for $ty {
+                fn random(rng: &mut Rng) -> Self {
+                    rng.next_u64() as $ty
+                }
+            }
+
+            impl RandomRange for $ty {
+                fn random_range(rng: &mut Rng, range: Range<Self>) -> Self {
+                    assert!(range.start < range.end, "Cannot generate a number in an empty range");
+                    let span = (range.end as i128 - range.start as i128) as u64;
+                    (range.start as i128 + rng.below(span) as i128) as $ty
+                }
+            }
+        )*
+    };
+}
+
+impl_random_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl Random for bool {
+    fn random(rng: &mut Rng) -> Self {
+        rng.next_u32() & 1 == 1
+    }
+}
+
+impl Random for f32 {
+    fn random(rng: &mut Rng) -> Self {
+        // Use the top 24 bits, which is the precision of an f32.
+        (rng.next_u32() >> 8) as f32 / (1u32 << 24) as f32
+    }
+}
+
+impl Random for f64 {
+    fn random(rng: &mut Rng) -> Self {
+        // Use the top 53 bits, which is the precision of an f64.
+        (rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+macro_rules! impl_random_range_float {
+    ($($ty:ty),*) => {
+        $(
+            impl RandomRange for $ty {
+                fn random_range(rng: &mut Rng, range: Range<Self>) -> Self {
+                    assert!(range.start < range.end, "Cannot generate a number in an empty range");
+                    range.start + (range.end - range.start) * <$ty>::random(rng)
+                }
+            }
+        )*
+    };
+}
+
+impl_random_range_float!(f32, f64);
+
+/// Reseeds the global generator, so that it produces the same numbers every time it is given the same seed.
+pub fn seed(seed: u64) {
+    *RNG.lock() = Rng::new(seed);
+}
+
+/// Returns a random value of type `T` from the global generator.
+pub fn random<T: Random>() -> T {
+    RNG.lock().random()
+}
+
+/// Returns a random value in the given range from the global generator.
+///
+/// # Panics
+///
+/// This function panics if the range is empty.
+pub fn random_range<T: RandomRange>(range: Range<T>) -> T {
+    RNG.lock().random_range(range)
+}
@@ -1,10 +1,28 @@
 //! Synchronization types for FreeRTOS tasks.
 //!
 //! Types implemented here are specifically designed to mimic the standard library.
+//! [`AsyncMutex`] is an alternative to [`Mutex`] for use in the async runtime.
+//! [`RwLock`] allows many tasks to read shared data at once while still giving writers exclusive access.
+//! [`Once`] and [`Lazy`] run initialization code exactly once, even when multiple tasks race to do it.
+//! [`Notify`] signals a waiting task or future that something has happened.
 
-use core::{cell::UnsafeCell, fmt::Debug, mem};
+use alloc::collections::VecDeque;
+use core::{
+    cell::UnsafeCell,
+    fmt::Debug,
+    future::Future,
+    mem,
+    ops::{Deref, DerefMut},
+    pin::Pin,
+    sync::atomic::{AtomicU8, Ordering},
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
 
-use crate::error::take_errno;
+use crate::{
+    error::take_errno,
+    task::{SchedulerSuspendGuard, TaskHandle},
+};
 
 /// The basic mutex type.
 /// Mutexes are used to share variables between tasks safely.
@@ -128,3 +146,799 @@ impl<T> Drop for MutexGuard<'_, T> {
         }
     }
 }
+
+/// A reader-writer lock.
+///
+/// Any number of tasks can hold a read lock at the same time, but a write lock
+/// is exclusive of both readers and other writers.
+///
+/// # Fairness
+///
+/// Writers are preferred over readers. Once a task starts waiting for a write lock, tasks that try to
+/// acquire a new read lock wait until that writer has finished, so a steady stream of readers cannot
+/// starve a writer. Readers and writers that arrive while a writer holds or waits for the lock are let in
+/// in the order FreeRTOS wakes them, which favors higher priority tasks.
+pub struct RwLock<T> {
+    /// Held by writers while they wait for and hold the lock, which stops new readers from entering.
+    turnstile: pros_sys::mutex_t,
+    /// Protects `readers`.
+    readers_lock: pros_sys::mutex_t,
+    /// Available when no task holds the lock.
+    /// The first reader in takes it and the last reader out gives it back.
+    room_empty: pros_sys::apix::sem_t,
+    readers: UnsafeCell<u32>,
+    data: Option<UnsafeCell<T>>,
+}
+unsafe impl<T: Send> Send for RwLock<T> {}
+unsafe impl<T: Send + Sync> Sync for RwLock<T> {}
+
+impl<T> RwLock<T> {
+    /// Creates a new reader-writer lock.
+    pub fn new(data: T) -> Self {
+        unsafe {
+            Self {
+                turnstile: pros_sys::mutex_create(),
+                readers_lock: pros_sys::mutex_create(),
+                room_empty: pros_sys::apix::sem_create(1, 1),
+                readers: UnsafeCell::new(0),
+                data: Some(UnsafeCell::new(data)),
+            }
+        }
+    }
+
+    /// Locks this lock with shared read access, blocking the current task until it can be acquired.
+    ///
+    /// Other readers may hold the lock at the same time.
+    pub fn read(&self) -> RwLockReadGuard<'_, T> {
+        self.try_read_timeout(pros_sys::TIMEOUT_MAX)
+            .unwrap_or_else(|| panic!("RwLock read lock failed: {}", take_errno()))
+    }
+
+    /// Attempts to lock this lock with shared read access. This function does not block.
+    pub fn try_read(&self) -> Option<RwLockReadGuard<'_, T>> {
+        self.try_read_timeout(0)
+    }
+
+    fn try_read_timeout(&self, timeout: u32) -> Option<RwLockReadGuard<'_, T>> {
+        // Wait for any writer that is already queued.
+        if !unsafe { pros_sys::mutex_take(self.turnstile, timeout) } {
+            return None;
+        }
+        unsafe {
+            pros_sys::mutex_give(self.turnstile);
+        }
+
+        if !unsafe { pros_sys::mutex_take(self.readers_lock, timeout) } {
+            return None;
+        }
+        let readers = unsafe { &mut *self.readers.get() };
+        let entered = *readers > 0 || unsafe { pros_sys::apix::sem_wait(self.room_empty, timeout) };
+        if entered {
+            *readers += 1;
+        }
+        unsafe {
+            pros_sys::mutex_give(self.readers_lock);
+        }
+
+        entered.then_some(RwLockReadGuard { lock: self })
+    }
+
+    /// Locks this lock with exclusive write access, blocking the current task until it can be acquired.
+    pub fn write(&self) -> RwLockWriteGuard<'_, T> {
+        self.try_write_timeout(pros_sys::TIMEOUT_MAX)
+            .unwrap_or_else(|| panic!("RwLock write lock failed: {}", take_errno()))
+    }
+
+    /// Attempts to lock this lock with exclusive write access. This function does not block.
+    pub fn try_write(&self) -> Option<RwLockWriteGuard<'_, T>> {
+        self.try_write_timeout(0)
+    }
+
+    fn try_write_timeout(&self, timeout: u32) -> Option<RwLockWriteGuard<'_, T>> {
+        if !unsafe { pros_sys::mutex_take(self.turnstile, timeout) } {
+            return None;
+        }
+        if !unsafe { pros_sys::apix::sem_wait(self.room_empty, timeout) } {
+            unsafe {
+                pros_sys::mutex_give(self.turnstile);
+            }
+            return None;
+        }
+
+        Some(RwLockWriteGuard { lock: self })
+    }
+
+    pub fn into_inner(mut self) -> T {
+        let data = mem::take(&mut self.data).unwrap();
+        data.into_inner()
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        self.data.as_mut().unwrap().get_mut()
+    }
+
+    fn data(&self) -> *mut T {
+        self.data.as_ref().unwrap().get()
+    }
+}
+
+impl<T> Drop for RwLock<T> {
+    fn drop(&mut self) {
+        unsafe {
+            pros_sys::mutex_delete(self.turnstile);
+            pros_sys::mutex_delete(self.readers_lock);
+            pros_sys::apix::sem_delete(self.room_empty);
+        }
+    }
+}
+
+impl<T> Debug for RwLock<T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        struct Placeholder;
+        impl Debug for Placeholder {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("<locked>")
+            }
+        }
+
+        let mut d = f.debug_struct("RwLock");
+        match self.try_read() {
+            Some(guard) => d.field("data", &&*guard),
+            None => d.field("data", &Placeholder),
+        };
+        d.finish_non_exhaustive()
+    }
+}
+
+impl<T> Default for RwLock<T>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> From<T> for RwLock<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Allows the user to read the data from an [`RwLock`] locked with [`RwLock::read`].
+/// Dereference to get the inner data.
+pub struct RwLockReadGuard<'a, T> {
+    lock: &'a RwLock<T>,
+}
+
+impl<T> Deref for RwLockReadGuard<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.data() }
+    }
+}
+
+impl<T> Drop for RwLockReadGuard<'_, T> {
+    fn drop(&mut self) {
+        unsafe {
+            pros_sys::mutex_take(self.lock.readers_lock, pros_sys::TIMEOUT_MAX);
+            let readers = &mut *self.lock.readers.get();
+            *readers -= 1;
+            if *readers == 0 {
+                pros_sys::apix::sem_post(self.lock.room_empty);
+            }
+            pros_sys::mutex_give(self.lock.readers_lock);
+        }
+    }
+}
+
+/// Allows the user to access the data from an [`RwLock`] locked with [`RwLock::write`].
+/// Dereference to get the inner data.
+pub struct RwLockWriteGuard<'a, T> {
+    lock: &'a RwLock<T>,
+}
+
+// FreeRTOS mutexes must be released by the task that took them.
+impl<T> !Send for RwLockWriteGuard<'_, T> {}
+
+impl<T> Deref for RwLockWriteGuard<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.data() }
+    }
+}
+
+impl<T> DerefMut for RwLockWriteGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.lock.data() }
+    }
+}
+
+impl<T> Drop for RwLockWriteGuard<'_, T> {
+    fn drop(&mut self) {
+        unsafe {
+            pros_sys::apix::sem_post(self.lock.room_empty);
+            pros_sys::mutex_give(self.lock.turnstile);
+        }
+    }
+}
+
+/// A lock for short critical sections that suspends the scheduler while it is held.
+///
+/// A spinlock can deadlock on the V5's single core: if a higher priority task preempts the
+/// holder and then spins on the same lock, the holder never runs again to release it.
+/// Suspending the scheduler stops any other task from running until the lock is released.
+///
+/// Nothing that blocks or yields (e.g. [`delay`](crate::task::delay)) may be called while the lock is held,
+/// and the same lock must not be locked again before the guard is dropped.
+pub(crate) struct SchedulerLock<T> {
+    data: UnsafeCell<T>,
+}
+unsafe impl<T: Send> Send for SchedulerLock<T> {}
+unsafe impl<T: Send> Sync for SchedulerLock<T> {}
+
+impl<T> SchedulerLock<T> {
+    pub(crate) const fn new(data: T) -> Self {
+        Self {
+            data: UnsafeCell::new(data),
+        }
+    }
+
+    pub(crate) fn lock(&self) -> SchedulerLockGuard<'_, T> {
+        SchedulerLockGuard {
+            lock: self,
+            // SAFETY: The guard is only used to access the data, which does not block or yield.
+            _suspend: unsafe { crate::task::suspend_all() },
+        }
+    }
+}
+
+pub(crate) struct SchedulerLockGuard<'a, T> {
+    lock: &'a SchedulerLock<T>,
+    _suspend: SchedulerSuspendGuard,
+}
+
+impl<T> !Send for SchedulerLockGuard<'_, T> {}
+
+impl<T> Deref for SchedulerLockGuard<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+impl<T> DerefMut for SchedulerLockGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.lock.data.get() }
+    }
+}
+
+struct AsyncMutexState {
+    locked: bool,
+    waiters: VecDeque<Waker>,
+}
+
+impl AsyncMutexState {
+    fn remove_waiter(&mut self, waker: &Waker) {
+        self.waiters.retain(|waiter| !waiter.will_wake(waker));
+    }
+}
+
+/// A mutex that yields to the async executor while waiting for the lock.
+///
+/// Locking a [`Mutex`] blocks the entire FreeRTOS task, including every other future on the async runtime.
+/// This mutex instead registers the waiting future and wakes it when the lock is released,
+/// which lets other futures run in the meantime.
+pub struct AsyncMutex<T: ?Sized> {
+    state: SchedulerLock<AsyncMutexState>,
+    data: UnsafeCell<T>,
+}
+unsafe impl<T: ?Sized + Send> Send for AsyncMutex<T> {}
+unsafe impl<T: ?Sized + Send> Sync for AsyncMutex<T> {}
+
+impl<T> AsyncMutex<T> {
+    /// Creates a new async mutex.
+    pub const fn new(data: T) -> Self {
+        Self {
+            state: SchedulerLock::new(AsyncMutexState {
+                locked: false,
+                waiters: VecDeque::new(),
+            }),
+            data: UnsafeCell::new(data),
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.data.into_inner()
+    }
+}
+
+impl<T: ?Sized> AsyncMutex<T> {
+    /// Locks the mutex, waiting until it is released if it is already locked.
+    ///
+    /// Waiting futures are woken in the order they started waiting.
+    pub fn lock(&self) -> AsyncMutexLockFuture<'_, T> {
+        AsyncMutexLockFuture {
+            mutex: self,
+            waker: None,
+        }
+    }
+
+    /// Attempts to acquire this lock without waiting.
+    pub fn try_lock(&self) -> Option<AsyncMutexGuard<'_, T>> {
+        let mut state = self.state.lock();
+        if state.locked {
+            return None;
+        }
+        state.locked = true;
+        Some(AsyncMutexGuard { mutex: self })
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        self.data.get_mut()
+    }
+}
+
+impl<T: ?Sized> Debug for AsyncMutex<T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        struct Placeholder;
+        impl Debug for Placeholder {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("<locked>")
+            }
+        }
+
+        let mut d = f.debug_struct("AsyncMutex");
+        match self.try_lock() {
+            Some(guard) => d.field("data", &&*guard),
+            None => d.field("data", &Placeholder),
+        };
+        d.finish_non_exhaustive()
+    }
+}
+
+impl<T> Default for AsyncMutex<T>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> From<T> for AsyncMutex<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+/// A future that resolves once an [`AsyncMutex`] has been locked. See [`AsyncMutex::lock`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct AsyncMutexLockFuture<'a, T: ?Sized> {
+    mutex: &'a AsyncMutex<T>,
+    waker: Option<Waker>,
+}
+
+impl<'a, T: ?Sized> Future for AsyncMutexLockFuture<'a, T> {
+    type Output = AsyncMutexGuard<'a, T>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.mutex.state.lock();
+
+        if !state.locked {
+            state.locked = true;
+            if let Some(waker) = &self.waker {
+                state.remove_waiter(waker);
+            }
+            drop(state);
+            self.waker = None;
+            return Poll::Ready(AsyncMutexGuard { mutex: self.mutex });
+        }
+
+        // Avoid queueing the same future more than once if it is polled again before being woken.
+        if let Some(waker) = &self.waker {
+            state.remove_waiter(waker);
+        }
+        state.waiters.push_back(cx.waker().clone());
+        drop(state);
+        self.waker = Some(cx.waker().clone());
+
+        Poll::Pending
+    }
+}
+
+impl<T: ?Sized> Drop for AsyncMutexLockFuture<'_, T> {
+    fn drop(&mut self) {
+        if let Some(waker) = &self.waker {
+            let next = {
+                let mut state = self.mutex.state.lock();
+                state.remove_waiter(waker);
+                // This future may have been woken to take the lock, so pass the wakeup on.
+                if state.locked {
+                    None
+                } else {
+                    state.waiters.pop_front()
+                }
+            };
+            if let Some(next) = next {
+                next.wake();
+            }
+        }
+    }
+}
+
+/// Allows the user to access the data from a locked [`AsyncMutex`].
+/// Dereference to get the inner data.
+pub struct AsyncMutexGuard<'a, T: ?Sized> {
+    mutex: &'a AsyncMutex<T>,
+}
+
+unsafe impl<T: ?Sized + Send + Sync> Sync for AsyncMutexGuard<'_, T> {}
+
+impl<T: ?Sized> core::ops::Deref for AsyncMutexGuard<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        unsafe { &*self.mutex.data.get() }
+    }
+}
+
+impl<T: ?Sized> core::ops::DerefMut for AsyncMutexGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.mutex.data.get() }
+    }
+}
+
+impl<T: ?Sized> Drop for AsyncMutexGuard<'_, T> {
+    fn drop(&mut self) {
+        let next = {
+            let mut state = self.mutex.state.lock();
+            state.locked = false;
+            state.waiters.pop_front()
+        };
+        if let Some(next) = next {
+            next.wake();
+        }
+    }
+}
+
+const ONCE_INCOMPLETE: u8 = 0;
+const ONCE_RUNNING: u8 = 1;
+const ONCE_COMPLETE: u8 = 2;
+
+/// A synchronization primitive which runs a piece of code exactly once.
+///
+/// Unlike [`Mutex`], this can be created in a `const` context, so it can be stored in a `static`.
+///
+/// ```ignore
+/// static SETUP: Once = Once::new();
+///
+/// SETUP.call_once(|| println!("This is only printed once"));
+/// ```
+pub struct Once {
+    state: AtomicU8,
+}
+
+impl Once {
+    /// Creates a new `Once` that has not run yet.
+    pub const fn new() -> Self {
+        Self {
+            state: AtomicU8::new(ONCE_INCOMPLETE),
+        }
+    }
+
+    /// Runs `f` if this is the first time `call_once` has been called.
+    ///
+    /// If another task is currently running its closure, this blocks the current task until it has finished,
+    /// so `f` has always completed by the time this returns.
+    /// Calling `call_once` on the same `Once` from inside `f` will block forever.
+    pub fn call_once(&self, f: impl FnOnce()) {
+        if self.is_completed() {
+            return;
+        }
+
+        match self.state.compare_exchange(
+            ONCE_INCOMPLETE,
+            ONCE_RUNNING,
+            Ordering::Acquire,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => {
+                f();
+                self.state.store(ONCE_COMPLETE, Ordering::Release);
+            }
+            Err(_) => {
+                // Sleep rather than spin so that a lower priority task running `f` can finish.
+                while !self.is_completed() {
+                    crate::task::delay(Duration::from_millis(1));
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if a call to [`Once::call_once`] has completed.
+    pub fn is_completed(&self) -> bool {
+        self.state.load(Ordering::Acquire) == ONCE_COMPLETE
+    }
+}
+
+impl Default for Once {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for Once {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Once")
+            .field("completed", &self.is_completed())
+            .finish()
+    }
+}
+
+/// A value which is initialized the first time it is accessed.
+///
+/// The initializer runs exactly once, in whichever task accesses the value first.
+/// Other tasks that access the value while it is being initialized wait for it to finish.
+///
+/// ```ignore
+/// static TELEMETRY: Lazy<Mutex<Telemetry>> = Lazy::new(|| Mutex::new(Telemetry::default()));
+///
+/// TELEMETRY.lock().record(sample);
+/// ```
+pub struct Lazy<T, F = fn() -> T> {
+    once: Once,
+    init: UnsafeCell<Option<F>>,
+    value: UnsafeCell<Option<T>>,
+}
+unsafe impl<T: Send + Sync, F: Send> Sync for Lazy<T, F> {}
+
+impl<T, F> Lazy<T, F> {
+    /// Creates a new lazy value with the given initializer.
+    pub const fn new(init: F) -> Self {
+        Self {
+            once: Once::new(),
+            init: UnsafeCell::new(Some(init)),
+            value: UnsafeCell::new(None),
+        }
+    }
+}
+
+impl<T, F: FnOnce() -> T> Lazy<T, F> {
+    /// Initializes the value if it has not been initialized yet, and returns a reference to it.
+    ///
+    /// This is equivalent to dereferencing the `Lazy`.
+    pub fn force(this: &Self) -> &T {
+        this.once.call_once(|| {
+            // SAFETY: `Once` guarantees that only one task runs this closure,
+            // and nothing reads `init` or `value` until it has finished.
+            unsafe {
+                let init = (*this.init.get())
+                    .take()
+                    .expect("Lazy initializer should only run once");
+                *this.value.get() = Some(init());
+            }
+        });
+
+        // SAFETY: The value was initialized above and is never written to again.
+        unsafe { (*this.value.get()).as_ref() }.expect("Lazy value should be initialized")
+    }
+}
+
+impl<T, F: FnOnce() -> T> Deref for Lazy<T, F> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        Lazy::force(self)
+    }
+}
+
+impl<T: Default> Default for Lazy<T> {
+    fn default() -> Self {
+        Self::new(T::default)
+    }
+}
+
+impl<T: Debug, F> Debug for Lazy<T, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let value = if self.once.is_completed() {
+            unsafe { (*self.value.get()).as_ref() }
+        } else {
+            None
+        };
+
+        let mut d = f.debug_struct("Lazy");
+        match value {
+            Some(value) => d.field("value", value),
+            None => d.field("value", &format_args!("<uninitialized>")),
+        };
+        d.finish_non_exhaustive()
+    }
+}
+
+enum NotifyWaiter {
+    Task(TaskHandle),
+    Future(Waker),
+}
+
+struct NotifyState {
+    /// Set when [`Notify::notify_one`] is called with nobody waiting,
+    /// so that the next waiter returns immediately.
+    permit: bool,
+    next_id: u32,
+    waiters: VecDeque<(u32, NotifyWaiter)>,
+}
+
+impl NotifyState {
+    fn register(&mut self, waiter: NotifyWaiter) -> u32 {
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+        self.waiters.push_back((id, waiter));
+        id
+    }
+
+    fn position(&self, id: u32) -> Option<usize> {
+        self.waiters.iter().position(|(waiter, _)| *waiter == id)
+    }
+}
+
+/// Notifies a single task or future that an event has happened.
+///
+/// Each call to [`Notify::notify_one`] wakes one waiter, in the order they started waiting.
+/// If nothing is waiting, the notification is stored and the next waiter returns immediately.
+/// Multiple notifications while nothing is waiting are combined into one.
+///
+/// ```ignore
+/// static CALIBRATED: Notify = Notify::new();
+///
+/// // In a background task
+/// imu.calibrate().await?;
+/// CALIBRATED.notify_one();
+///
+/// // In the autonomous routine
+/// CALIBRATED.notified().await;
+/// ```
+pub struct Notify {
+    state: SchedulerLock<NotifyState>,
+}
+
+impl Notify {
+    /// Creates a new `Notify` with nothing waiting and no stored notification.
+    pub const fn new() -> Self {
+        Self {
+            state: SchedulerLock::new(NotifyState {
+                permit: false,
+                next_id: 0,
+                waiters: VecDeque::new(),
+            }),
+        }
+    }
+
+    /// Wakes the task or future that has been waiting the longest,
+    /// or stores the notification for the next waiter if nothing is waiting.
+    pub fn notify_one(&self) {
+        let mut state = self.state.lock();
+        match state.waiters.pop_front() {
+            Some((_, waiter)) => {
+                drop(state);
+                match waiter {
+                    NotifyWaiter::Task(task) => task.notify(),
+                    NotifyWaiter::Future(waker) => waker.wake(),
+                }
+            }
+            None => state.permit = true,
+        }
+    }
+
+    /// Returns a future that completes when this is notified.
+    pub fn notified(&self) -> NotifiedFuture<'_> {
+        NotifiedFuture {
+            notify: self,
+            state: NotifiedState::Init,
+        }
+    }
+
+    /// Blocks the current task until this is notified.
+    ///
+    /// This uses FreeRTOS task notifications to wake the task, so it should not be combined
+    /// with [`get_notification`](crate::task::get_notification) in the same task.
+    pub fn wait(&self) {
+        let current = crate::task::current();
+        let id = {
+            let mut state = self.state.lock();
+            if mem::take(&mut state.permit) {
+                return;
+            }
+            state.register(NotifyWaiter::Task(current))
+        };
+
+        // The task may be notified for other reasons, so check that this waiter was actually removed.
+        loop {
+            crate::task::get_notification();
+            if self.state.lock().position(id).is_none() {
+                return;
+            }
+        }
+    }
+}
+
+impl Default for Notify {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for Notify {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Notify").finish_non_exhaustive()
+    }
+}
+
+enum NotifiedState {
+    Init,
+    Waiting(u32),
+    Done,
+}
+
+/// A future that completes when a [`Notify`] is notified. See [`Notify::notified`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct NotifiedFuture<'a> {
+    notify: &'a Notify,
+    state: NotifiedState,
+}
+
+impl<'a> Future for NotifiedFuture<'a> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.notify.state.lock();
+        match self.state {
+            NotifiedState::Init => {
+                if mem::take(&mut state.permit) {
+                    drop(state);
+                    self.state = NotifiedState::Done;
+                    return Poll::Ready(());
+                }
+                let id = state.register(NotifyWaiter::Future(cx.waker().clone()));
+                drop(state);
+                self.state = NotifiedState::Waiting(id);
+                Poll::Pending
+            }
+            NotifiedState::Waiting(id) => match state.position(id) {
+                Some(index) => {
+                    state.waiters[index].1 = NotifyWaiter::Future(cx.waker().clone());
+                    Poll::Pending
+                }
+                None => {
+                    drop(state);
+                    self.state = NotifiedState::Done;
+                    Poll::Ready(())
+                }
+            },
+            NotifiedState::Done => Poll::Ready(()),
+        }
+    }
+}
+
+impl Drop for NotifiedFuture<'_> {
+    fn drop(&mut self) {
+        if let NotifiedState::Waiting(id) = self.state {
+            let mut state = self.notify.state.lock();
+            match state.position(id) {
+                Some(index) => {
+                    state.waiters.remove(index);
+                }
+                // This future was notified but dropped before seeing it, so pass the notification on.
+                None => {
+                    drop(state);
+                    self.notify.notify_one();
+                }
+            }
+        }
+    }
+}
@@ -22,14 +22,21 @@ pub mod local;
 use alloc::{
     boxed::Box,
     string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use core::{
+    cell::RefCell, ffi::CStr, future::Future, hash::Hash, marker::PhantomData, mem, str::Utf8Error,
+    task::Poll, time::Duration,
 };
-use core::{ffi::CStr, future::Future, hash::Hash, str::Utf8Error, task::Poll, time::Duration};
 
 use snafu::Snafu;
 
 use crate::{
     async_runtime::executor::EXECUTOR,
     error::{bail_on, map_errno},
+    os_task_local,
+    sync::Mutex,
 };
 
 /// Creates a task to be run 'asynchronously' (More information at the [FreeRTOS docs](https://www.freertos.org/taskandcr.html)).
@@ -43,6 +50,79 @@ where
     Builder::new().spawn(f).expect("Failed to spawn task")
 }
 
+/// Creates a scope for spawning tasks that can borrow data from the current task.
+///
+/// Tasks spawned with [`Scope::spawn`] do not need to be `'static`, since every one of them
+/// is joined before this function returns.
+///
+/// ```rust
+/// # use pros::task;
+/// let mut readings = [0.0; 2];
+/// let (left, right) = readings.split_at_mut(1);
+/// task::scope(|s| {
+///     s.spawn(|| left[0] = 1.0);
+///     s.spawn(|| right[0] = 2.0);
+/// });
+/// assert_eq!(readings, [1.0, 2.0]);
+/// ```
+pub fn scope<'env, F, T>(f: F) -> T
+where
+    F: for<'scope> FnOnce(&'scope Scope<'scope, 'env>) -> T,
+{
+    // A panic that deletes this task instead of ending the program would free borrowed data
+    // while scoped tasks are still using it, so panics are not reported to a join handle inside a scope.
+    let panic_slot = PANIC_SLOT.take();
+
+    let scope = Scope {
+        tasks: Mutex::new(Vec::new()),
+        scope: PhantomData,
+        env: PhantomData,
+    };
+    let result = f(&scope);
+
+    // Tasks can spawn more tasks in the scope, so keep joining until there are none left.
+    loop {
+        let tasks = mem::take(&mut *scope.tasks.lock());
+        if tasks.is_empty() {
+            break;
+        }
+        for task in tasks {
+            task.join();
+        }
+    }
+
+    PANIC_SLOT.set(panic_slot);
+    result
+}
+
+/// A scope for spawning tasks that borrow data. Created by [`scope`].
+pub struct Scope<'scope, 'env: 'scope> {
+    tasks: Mutex<Vec<TaskHandle>>,
+    // Invariant lifetimes, matching `std::thread::Scope`.
+    scope: PhantomData<&'scope mut &'scope ()>,
+    env: PhantomData<&'env mut &'env ()>,
+}
+
+impl<'scope, 'env> Scope<'scope, 'env> {
+    /// Spawns a task that can borrow data from outside of the scope.
+    ///
+    /// The task is joined before [`scope`] returns.
+    pub fn spawn<F>(&'scope self, function: F)
+    where
+        F: FnOnce() + Send + 'scope,
+    {
+        let function: Box<dyn FnOnce() + Send + 'scope> = Box::new(function);
+        // SAFETY: The task is joined before `scope` returns, so it cannot outlive any borrowed data.
+        //         Panics end the program rather than unwinding, so the join cannot be skipped.
+        let function: Box<dyn FnOnce() + Send + 'static> = unsafe { mem::transmute(function) };
+
+        let task = Builder::new()
+            .spawn(function)
+            .expect("Failed to spawn task");
+        self.tasks.lock().push(task);
+    }
+}
+
 /// Low level task spawning functionality
 fn spawn_inner<F: FnOnce() + Send + 'static>(
     function: F,
@@ -115,6 +195,19 @@ impl TaskHandle {
         }
     }
 
+    /// Gets the task's priority.
+    pub fn priority(&self) -> u32 {
+        unsafe { pros_sys::task_get_priority(self.task) }
+    }
+
+    /// Gets the smallest amount of free stack space the task has had since it was created, in words.
+    ///
+    /// A value close to zero means that the task has nearly overflowed its stack,
+    /// and should be spawned with a larger [`TaskStackDepth`].
+    pub fn stack_high_water_mark(&self) -> u32 {
+        unsafe { pros_sys::uxTaskGetStackHighWaterMark(self.task) }
+    }
+
     /// Get the state of the task.
     pub fn state(&self) -> TaskState {
         unsafe { pros_sys::task_get_state(self.task).into() }
@@ -127,6 +220,17 @@ impl TaskHandle {
         }
     }
 
+    /// Sends a notification to the task, changing its notification value with `value` as described by `action`.
+    ///
+    /// Returns the task's notification value from before it was changed.
+    pub fn notify_with(&self, value: u32, action: NotifyAction) -> u32 {
+        let mut previous = 0;
+        unsafe {
+            pros_sys::task_notify_ext(self.task, value, action as _, &mut previous);
+        }
+        previous
+    }
+
     /// Waits for the task to finish, and then deletes it.
     pub fn join(self) {
         unsafe {
@@ -150,6 +254,47 @@ impl TaskHandle {
     }
 }
 
+/// Storage for the message of a panic in a task spawned with [`Builder::spawn_with_result`].
+pub(crate) type PanicSlot = Arc<Mutex<Option<String>>>;
+
+os_task_local! {
+    /// Set in tasks whose panics should be reported to a [`JoinHandle`] rather than ending the program.
+    pub(crate) static PANIC_SLOT: RefCell<Option<PanicSlot>> = RefCell::new(None);
+}
+
+/// A handle to a task that returns a value, created by [`Builder::spawn_with_result`].
+pub struct JoinHandle<T> {
+    task: TaskHandle,
+    result: Arc<Mutex<Option<T>>>,
+    panic: PanicSlot,
+}
+
+impl<T> JoinHandle<T> {
+    /// Returns the handle of the underlying task.
+    pub fn task(&self) -> &TaskHandle {
+        &self.task
+    }
+
+    /// Waits for the task to finish, and then returns the value it produced.
+    ///
+    /// If the task panicked, its panic message is returned as a [`TaskPanic`] instead.
+    /// Any locks the task held when it panicked are never released; see [`Builder::spawn_with_result`].
+    pub fn join(self) -> Result<T, TaskPanic> {
+        let Self {
+            task,
+            result,
+            panic,
+        } = self;
+        task.join();
+
+        if let Some(message) = panic.lock().take() {
+            return Err(TaskPanic { message });
+        }
+        let value = result.lock().take();
+        Ok(value.expect("task finished without producing a result"))
+    }
+}
+
 /// An ergonomic builder for tasks. Alternatively you can use [`spawn`].
 #[derive(Default)]
 pub struct Builder<'a> {
@@ -195,6 +340,44 @@ impl<'a> Builder<'a> {
             self.name,
         )
     }
+
+    /// Builds and spawns a task whose return value can be retrieved with [`JoinHandle::join`].
+    ///
+    /// Unlike other tasks, a panic in this task does not end the program.
+    /// Instead, the task is deleted and the panic is reported by [`JoinHandle::join`].
+    ///
+    /// # Deadlocks
+    ///
+    /// Panics are not unwound, so a panicking task is deleted without running any destructors.
+    /// Anything it owns is leaked, and **any lock it holds is never released**. This includes
+    /// [`MutexGuard`](crate::sync::MutexGuard)s and mutexes or semaphores taken through `pros_sys`.
+    /// Every other task that tries to take one of those locks will then wait forever.
+    ///
+    /// Only rely on this to recover from panics in tasks that do not hold locks shared with
+    /// the rest of the program. Otherwise, treat a [`TaskPanic`] as fatal.
+    pub fn spawn_with_result<F, T>(self, function: F) -> Result<JoinHandle<T>, SpawnError>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let result = Arc::new(Mutex::new(None));
+        let panic = PanicSlot::default();
+        let task = self.spawn({
+            let result = result.clone();
+            let panic = panic.clone();
+            move || {
+                PANIC_SLOT.set(Some(panic));
+                let value = function();
+                *result.lock() = Some(value);
+            }
+        })?;
+
+        Ok(JoinHandle {
+            task,
+            result,
+            panic,
+        })
+    }
 }
 
 /// Represents the current state of a task.
@@ -284,6 +467,16 @@ pub enum SpawnError {
     TCBNotCreated,
 }
 
+/// The error returned by [`JoinHandle::join`] when the task panicked.
+///
+/// The task was deleted without releasing any locks it held, so other tasks may be deadlocked.
+#[derive(Debug, Snafu)]
+#[snafu(display("{message}"))]
+pub struct TaskPanic {
+    /// The message printed when the task panicked.
+    pub message: String,
+}
+
 map_errno! {
     SpawnError {
         ENOMEM => SpawnError::TCBNotCreated,
@@ -311,7 +504,7 @@ impl Interval {
     /// will become smaller so that the average rate is maintained.
     pub fn start() -> Self {
         Self {
-            last_unblock_time: unsafe { pros_sys::millis() },
+            last_unblock_time: crate::time::clock().millis(),
         }
     }
 
@@ -321,12 +514,31 @@ impl Interval {
     ///
     /// This function will block the entire task, preventing concurrent
     /// execution of async code. When in an async context, it is recommended
-    /// to an async-friendly equivalent instead.
+    /// to use [`Interval::tick`] instead.
     pub fn delay(&mut self, delta: Duration) {
-        let delta = delta.as_millis() as u32;
-        unsafe {
-            // PROS handles loop overruns so there's no need to check for them here
-            pros_sys::task_delay_until((&mut self.last_unblock_time) as *mut _, delta);
+        self.last_unblock_time = self
+            .last_unblock_time
+            .wrapping_add(delta.as_millis() as u32);
+
+        // If the loop overran, the next interval starts immediately.
+        let remaining = self
+            .last_unblock_time
+            .wrapping_sub(crate::time::clock().millis()) as i32;
+        if remaining > 0 {
+            unsafe { pros_sys::delay(remaining as u32) }
+        }
+    }
+
+    /// Returns a future that completes once the interval has elapsed, without blocking the task.
+    ///
+    /// Like [`Interval::delay`], the interval is measured from the end of the previous interval
+    /// rather than from when this is called, so the average rate is maintained.
+    pub fn tick(&mut self, delta: Duration) -> SleepFuture {
+        self.last_unblock_time = self
+            .last_unblock_time
+            .wrapping_add(delta.as_millis() as u32);
+        SleepFuture {
+            target_millis: self.last_unblock_time,
         }
     }
 }
@@ -343,7 +555,7 @@ impl Future for SleepFuture {
         self: core::pin::Pin<&mut Self>,
         cx: &mut core::task::Context<'_>,
     ) -> core::task::Poll<Self::Output> {
-        if self.target_millis < unsafe { pros_sys::millis() } {
+        if self.target_millis < crate::time::clock().millis() {
             Poll::Ready(())
         } else {
             EXECUTOR.with(|e| {
@@ -360,10 +572,17 @@ impl Future for SleepFuture {
 /// Returns a future that will complete after the given duration.
 pub fn sleep(duration: core::time::Duration) -> SleepFuture {
     SleepFuture {
-        target_millis: unsafe { pros_sys::millis() + duration.as_millis() as u32 },
+        target_millis: crate::time::clock().millis() + duration.as_millis() as u32,
     }
 }
 
+/// Gets the number of tasks the kernel is currently managing.
+///
+/// This includes every ready, blocked, or suspended task, as well as deleted tasks that have not been cleaned up yet.
+pub fn count() -> u32 {
+    unsafe { pros_sys::task_get_count() }
+}
+
 /// Returns the task the function was called from.
 pub fn current() -> TaskHandle {
     unsafe {
@@ -380,6 +599,31 @@ pub fn get_notification() -> u32 {
     unsafe { pros_sys::task_notify_take(false, pros_sys::TIMEOUT_MAX) }
 }
 
+/// Waits up to `timeout` for a notification, returning the notification value if one was received.
+///
+/// Like [`get_notification`], the notification value is decremented after being read.
+pub fn notification_wait_timeout(timeout: Duration) -> Option<u32> {
+    let timeout = u32::try_from(timeout.as_millis()).unwrap_or(u32::MAX);
+    let value = unsafe { pros_sys::task_notify_take(false, timeout) };
+    (value != 0).then_some(value)
+}
+
+/// How a notification sent with [`TaskHandle::notify_with`] changes the task's notification value.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotifyAction {
+    /// Leaves the notification value unchanged.
+    None = pros_sys::E_NOTIFY_ACTION_NONE,
+    /// Sets the bits of `value` in the notification value with a bitwise OR.
+    SetBits = pros_sys::E_NOTIFY_ACTION_BITS,
+    /// Increments the notification value, ignoring `value`.
+    Increment = pros_sys::E_NOTIFY_ACTION_INCR,
+    /// Sets the notification value to `value`.
+    Overwrite = pros_sys::E_NOTIFY_ACTION_OWRITE,
+    /// Sets the notification value to `value` only if the task has no pending notification.
+    NoOverwrite = pros_sys::E_NOTIFY_ACTION_NO_OWRITE,
+}
+
 pub struct SchedulerSuspendGuard {
     _private: (),
 }
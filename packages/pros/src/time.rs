@@ -1,4 +1,9 @@
 //! Temporal quantification.
+//!
+//! All time reads in this crate, including [`Instant::now`], [`sleep`](crate::task::sleep),
+//! and [`Interval`](crate::task::Interval), go through the [`Clock`] returned by [`clock`].
+//! By default this is the [`SystemClock`], but a different clock can be installed with
+//! [`set_clock`], for example to replay a recorded autonomous routine.
 
 use core::{
     fmt,
@@ -6,11 +11,72 @@ use core::{
     time::Duration,
 };
 
+/// A source of the current time.
+pub trait Clock: Sync {
+    /// Returns the number of microseconds since the program started.
+    fn micros(&self) -> u64;
+
+    /// Returns the number of milliseconds since the program started.
+    fn millis(&self) -> u32 {
+        (self.micros() / 1000) as u32
+    }
+}
+
+/// The clock of the platform the program is running on.
+///
+/// On the brain this reads the hardware timer. When running under `pros-simulator`,
+/// the same functions are provided by the simulator, so this follows simulated time.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn micros(&self) -> u64 {
+        unsafe { pros_sys::rtos::micros() }
+    }
+
+    fn millis(&self) -> u32 {
+        unsafe { pros_sys::millis() }
+    }
+}
+
+static CLOCK: spin::Once<&'static dyn Clock> = spin::Once::new();
+
+/// Returns the clock used for all time reads, which is the [`SystemClock`] unless
+/// another clock has been installed with [`set_clock`].
+pub fn clock() -> &'static dyn Clock {
+    CLOCK.get().copied().unwrap_or(&SystemClock)
+}
+
+/// Installs the clock used for all time reads.
+///
+/// This should be called at the start of the program, since [`Instant`]s taken before the clock
+/// was changed cannot be compared with ones taken after. The clock can only be set once;
+/// if it has already been set, the given clock is returned as an error.
+///
+/// Blocking delays such as [`delay`](crate::task::delay) are handled by the FreeRTOS scheduler
+/// and always wait in real time.
+pub fn set_clock(clock: &'static dyn Clock) -> Result<(), &'static dyn Clock> {
+    let mut installed = false;
+    CLOCK.call_once(|| {
+        installed = true;
+        clock
+    });
+
+    if installed {
+        Ok(())
+    } else {
+        Err(clock)
+    }
+}
+
 /// Represents a timestamp on a monotonically nondecreasing clock relative to the
 /// start of the user program.
 ///
 /// # Precision
-/// This type has a precision of 1 microsecond, and uses [`pros_sys::micros`] internally.
+/// This type has a precision of 1 microsecond, and uses [`Clock::micros`] internally.
+///
+/// Because the microsecond count is stored in a [`u64`], it will not wrap around
+/// for hundreds of thousands of years, unlike the [`u32`] returned by [`pros_sys::millis`].
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Instant(u64);
 
@@ -25,7 +91,7 @@ impl Instant {
     /// let now = Instant::now();
     /// ```
     pub fn now() -> Self {
-        Self(unsafe { pros_sys::rtos::micros() })
+        Self(clock().micros())
     }
 
     /// Returns the amount of time elapsed from another instant to this one,
@@ -63,7 +129,7 @@ impl Instant {
     /// println!("{:?}", now.checked_duration_since(new_now)); // None
     /// ```
     pub fn checked_duration_since(&self, earlier: Instant) -> Option<Duration> {
-        if earlier.0 < self.0 {
+        if earlier.0 <= self.0 {
             Some(Duration::from_micros(self.0 - earlier.0))
         } else {
             None
@@ -79,10 +145,11 @@ impl Instant {
     /// use core::time::Duration;
     /// use pros::{time::Instant, task::delay};
     ///
-    /// let instant = Instant::now();
-    /// let three_secs = Duration::from_secs(3);
-    /// delay(three_secs);
-    /// assert!(instant.elapsed() >= three_secs);
+    /// let now = Instant::now();
+    /// delay(Duration::new(1, 0));
+    /// let new_now = Instant::now();
+    /// println!("{:?}", new_now.saturating_duration_since(now));
+    /// println!("{:?}", now.saturating_duration_since(new_now)); // 0ns
     /// ```
     pub fn saturating_duration_since(&self, earlier: Instant) -> Duration {
         self.checked_duration_since(earlier).unwrap_or_default()
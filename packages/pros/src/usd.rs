@@ -1,4 +1,373 @@
+//! Access to the SD card inserted into the brain.
+//!
+//! Files on the SD card can be read and written with [`File`], which implements the
+//! [`Read`], [`Write`], and [`Seek`] traits from [`crate::io`].
+//! Paths are relative to the root of the SD card, so `"logs/match.csv"` and `"/usd/logs/match.csv"`
+//! refer to the same file.
+//!
+//! Directories can be listed with [`read_dir`]. Deleting files is not supported by the PROS kernel.
+
+use alloc::{
+    ffi::CString,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::ffi::c_long;
+
+use snafu::Snafu;
+
+use crate::{
+    error::{map_errno, take_errno, FromErrno},
+    io::{self, Read, Seek, SeekFrom, Write},
+};
+
 /// Checks if an SD card is installed.
 pub fn usd_installed() -> bool {
     unsafe { pros_sys::misc::usd_is_installed() == 1 }
 }
+
+/// The maximum number of bytes of entry names that [`read_dir`] can list in one directory.
+pub const MAX_DIR_LISTING_LEN: usize = 4096;
+
+/// Strips the `/usd/` mount point and any leading slashes from a path.
+fn relative_path(path: &str) -> &str {
+    let path = path.strip_prefix("/usd").unwrap_or(path);
+    path.trim_start_matches('/')
+}
+
+/// Converts a path relative to the SD card into an absolute path on the `/usd/` mount.
+fn usd_path(path: &str) -> Result<CString, UsdError> {
+    if !usd_installed() {
+        return Err(UsdError::NotInstalled);
+    }
+
+    CString::new(format!("/usd/{}", relative_path(path))).map_err(|_| UsdError::InvalidPath)
+}
+
+/// Returns the `vexFileStatus` of a path relative to the SD card.
+fn file_status(path: &str) -> Result<u32, UsdError> {
+    let path = CString::new(relative_path(path)).map_err(|_| UsdError::InvalidPath)?;
+    Ok(unsafe { pros_sys::vexFileStatus(path.as_ptr()) })
+}
+
+/// Checks if a file or directory exists at `path`.
+///
+/// Returns false if no SD card is installed.
+pub fn exists(path: &str) -> bool {
+    usd_installed() && file_status(path).is_ok_and(|status| status != 0)
+}
+
+/// Lists the entries of the directory at `path`.
+///
+/// Only the first [`MAX_DIR_LISTING_LEN`] bytes of entry names are read.
+pub fn read_dir(path: &str) -> Result<ReadDir, UsdError> {
+    if !usd_installed() {
+        return Err(UsdError::NotInstalled);
+    }
+
+    let dir = relative_path(path).trim_end_matches('/');
+    let c_dir = CString::new(format!("/{dir}")).map_err(|_| UsdError::InvalidPath)?;
+    let mut buffer = vec![0u8; MAX_DIR_LISTING_LEN];
+    let result = unsafe {
+        pros_sys::vexFileDirectoryGet(
+            c_dir.as_ptr(),
+            buffer.as_mut_ptr().cast(),
+            MAX_DIR_LISTING_LEN as u32,
+        )
+    };
+    match result {
+        0 => {}
+        3 => return Err(UsdError::NotInstalled),
+        4 | 5 => return Err(UsdError::NotFound),
+        6 => return Err(UsdError::InvalidPath),
+        _ => return Err(UsdError::Io),
+    }
+
+    let len = buffer.iter().position(|&b| b == 0).unwrap_or(buffer.len());
+    let listing = String::from_utf8_lossy(&buffer[..len]);
+
+    let mut entries = Vec::new();
+    for name in listing.split('\n').filter(|name| !name.is_empty()) {
+        let entry_path = if dir.is_empty() {
+            name.to_string()
+        } else {
+            format!("{dir}/{name}")
+        };
+        entries.push(DirEntry {
+            name: name.to_string(),
+            is_dir: file_status(&entry_path)? == 2,
+        });
+    }
+
+    Ok(ReadDir {
+        entries: entries.into_iter(),
+    })
+}
+
+/// An iterator over the entries of a directory. Created by [`read_dir`].
+#[derive(Debug)]
+pub struct ReadDir {
+    entries: vec::IntoIter<DirEntry>,
+}
+
+impl Iterator for ReadDir {
+    type Item = DirEntry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries.next()
+    }
+}
+
+/// An entry in a directory on the SD card.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirEntry {
+    name: String,
+    is_dir: bool,
+}
+
+impl DirEntry {
+    /// Returns the name of the entry, without the path of its directory.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns true if the entry is a directory.
+    pub fn is_dir(&self) -> bool {
+        self.is_dir
+    }
+}
+
+/// Options for configuring how a [`File`] is opened.
+///
+/// Files opened with [`OpenOptions::truncate`] or [`OpenOptions::append`] are created if they do not exist.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpenOptions {
+    read: bool,
+    write: bool,
+    append: bool,
+    truncate: bool,
+}
+
+impl OpenOptions {
+    /// Creates a new set of options with every option disabled.
+    pub const fn new() -> Self {
+        Self {
+            read: false,
+            write: false,
+            append: false,
+            truncate: false,
+        }
+    }
+
+    /// Sets whether the file can be read from.
+    pub fn read(&mut self, read: bool) -> &mut Self {
+        self.read = read;
+        self
+    }
+
+    /// Sets whether the file can be written to.
+    ///
+    /// Without [`OpenOptions::truncate`] or [`OpenOptions::append`], the file must already exist
+    /// and writes will overwrite its contents starting from the beginning.
+    pub fn write(&mut self, write: bool) -> &mut Self {
+        self.write = write;
+        self
+    }
+
+    /// Sets whether writes should always be added to the end of the file.
+    ///
+    /// This implies [`OpenOptions::write`].
+    pub fn append(&mut self, append: bool) -> &mut Self {
+        self.append = append;
+        self
+    }
+
+    /// Sets whether the file should be emptied when it is opened.
+    ///
+    /// This only has an effect when the file is opened for writing.
+    pub fn truncate(&mut self, truncate: bool) -> &mut Self {
+        self.truncate = truncate;
+        self
+    }
+
+    /// Opens the file at `path` with these options.
+    pub fn open(&self, path: &str) -> Result<File, UsdError> {
+        let mode = match (self.read, self.write, self.append, self.truncate) {
+            (false, _, true, _) => c"ab",
+            (true, _, true, _) => c"a+b",
+            (false, true, false, true) => c"wb",
+            (true, true, false, true) => c"w+b",
+            (_, true, false, false) => c"r+b",
+            (true, false, false, _) => c"rb",
+            (false, false, false, _) => return Err(UsdError::InvalidOptions),
+        };
+        let path = usd_path(path)?;
+
+        let stream = unsafe { pros_sys::fopen(path.as_ptr(), mode.as_ptr()) };
+        if stream.is_null() {
+            return Err(UsdError::take());
+        }
+
+        Ok(File { stream })
+    }
+}
+
+/// A file on the SD card.
+///
+/// The file is closed when it is dropped.
+#[derive(Debug)]
+pub struct File {
+    stream: *mut pros_sys::FILE,
+}
+
+impl File {
+    /// Opens an existing file for reading.
+    pub fn open(path: &str) -> Result<Self, UsdError> {
+        OpenOptions::new().read(true).open(path)
+    }
+
+    /// Opens a file for writing, creating it if it does not exist and emptying it if it does.
+    pub fn create(path: &str) -> Result<Self, UsdError> {
+        OpenOptions::new().write(true).truncate(true).open(path)
+    }
+
+    /// Creates a blank set of options for opening a file. See [`OpenOptions`].
+    pub const fn options() -> OpenOptions {
+        OpenOptions::new()
+    }
+
+    fn stream_error(&self) -> bool {
+        unsafe { pros_sys::ferror(self.stream) != 0 }
+    }
+
+    /// Converts the error left in `errno` by a failed seek into an [`io::Error`].
+    fn seek_error(message: &'static str) -> io::Error {
+        let kind = match take_errno() {
+            0 => io::ErrorKind::Other,
+            errno => match UsdError::from_errno(errno) {
+                Some(UsdError::InvalidOptions) => io::ErrorKind::InvalidInput,
+                Some(UsdError::NotInstalled | UsdError::NotFound) => io::ErrorKind::NotFound,
+                _ => io::ErrorKind::Other,
+            },
+        };
+        io::Error::new(kind, message)
+    }
+}
+
+impl Read for File {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = unsafe { pros_sys::fread(buf.as_mut_ptr().cast(), 1, buf.len(), self.stream) };
+        if read < buf.len() && self.stream_error() {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                "Failed to read from file on SD card.",
+            ));
+        }
+        Ok(read)
+    }
+}
+
+impl Write for File {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = unsafe { pros_sys::fwrite(buf.as_ptr().cast(), 1, buf.len(), self.stream) };
+        if written < buf.len() && self.stream_error() {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                "Failed to write to file on SD card.",
+            ));
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if unsafe { pros_sys::fflush(self.stream) } != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                "Failed to flush file on SD card.",
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Seek for File {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let (offset, whence) = match pos {
+            SeekFrom::Start(offset) => (offset.try_into().ok(), pros_sys::SEEK_SET),
+            SeekFrom::Current(offset) => (offset.try_into().ok(), pros_sys::SEEK_CUR),
+            SeekFrom::End(offset) => (offset.try_into().ok(), pros_sys::SEEK_END),
+        };
+        let offset: c_long = offset.ok_or(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "Seek offset is too large.",
+        ))?;
+
+        if unsafe { pros_sys::fseek(self.stream, offset, whence) } != 0 {
+            return Err(Self::seek_error("Failed to seek in file on SD card."));
+        }
+
+        // Unlike most PROS functions, `ftell` returns -1 on failure.
+        let position = unsafe { pros_sys::ftell(self.stream) };
+        if position < 0 {
+            return Err(Self::seek_error(
+                "Failed to get position in file on SD card.",
+            ));
+        }
+        Ok(position as u64)
+    }
+}
+
+impl Drop for File {
+    fn drop(&mut self) {
+        unsafe {
+            pros_sys::fclose(self.stream);
+        }
+    }
+}
+
+#[derive(Debug, Snafu)]
+pub enum UsdError {
+    #[snafu(display("No SD card is installed."))]
+    NotInstalled,
+    #[snafu(display("The file or directory does not exist."))]
+    NotFound,
+    #[snafu(display("The file or directory already exists."))]
+    AlreadyExists,
+    #[snafu(display("Too many files are open at once."))]
+    TooManyOpenFiles,
+    #[snafu(display("The path is invalid."))]
+    InvalidPath,
+    #[snafu(display("A file must be opened for reading, writing, or appending."))]
+    InvalidOptions,
+    #[snafu(display("An I/O error occurred while accessing the SD card."))]
+    Io,
+    #[snafu(display("An unexpected error occurred while accessing the SD card (errno {errno})."))]
+    Unknown { errno: i32 },
+}
+
+impl UsdError {
+    /// Takes the error in `errno`, using [`UsdError::Unknown`] rather than panicking
+    /// for codes without a dedicated variant (e.g. ENOMEM).
+    fn take() -> Self {
+        let errno = take_errno();
+        if errno != 0 {
+            if let Some(err) = Self::from_errno(errno) {
+                return err;
+            }
+        }
+        Self::Unknown { errno }
+    }
+}
+
+map_errno! {
+    UsdError {
+        ENXIO => Self::NotInstalled,
+        ENOENT | ENOTDIR => Self::NotFound,
+        EEXIST => Self::AlreadyExists,
+        EMFILE | ENFILE => Self::TooManyOpenFiles,
+        EINVAL => Self::InvalidOptions,
+        EIO | ENOSPC | EROFS | EACCES | EISDIR => Self::Io,
+    }
+}
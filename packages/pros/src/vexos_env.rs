@@ -10,5 +10,11 @@ unsafe impl GlobalAlloc for Allocator {
     }
 }
 
+#[cfg(not(feature = "tracking-alloc"))]
 #[global_allocator]
 static ALLOCATOR: Allocator = Allocator;
+
+#[cfg(feature = "tracking-alloc")]
+#[global_allocator]
+static ALLOCATOR: crate::mem::TrackingAllocator<Allocator> =
+    crate::mem::TrackingAllocator(Allocator);
@@ -46,5 +46,11 @@ extern "C" fn wasm_free(ptr: *mut u8) {
     }
 }
 
+#[cfg(not(feature = "tracking-alloc"))]
 #[global_allocator]
 static ALLOCATOR: GlobalDlmalloc = GlobalDlmalloc;
+
+#[cfg(feature = "tracking-alloc")]
+#[global_allocator]
+static ALLOCATOR: crate::mem::TrackingAllocator<GlobalDlmalloc> =
+    crate::mem::TrackingAllocator(GlobalDlmalloc);